@@ -1,3 +1,8 @@
 import Config
 
 config :zenohex, :nif_logger, enable: false
+
+# NOTE: e.g. `ZENOHEX_NIF_FEATURES=plugin_storage_manager mix test --only plugin_storage_manager`
+#       builds the NIF with these cargo features and runs the tests tagged with them.
+config :zenohex,
+  nif_features: "ZENOHEX_NIF_FEATURES" |> System.get_env("") |> String.split(",", trim: true)
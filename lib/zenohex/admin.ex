@@ -0,0 +1,174 @@
+defmodule Zenohex.Admin do
+  @moduledoc """
+  Interface for browsing the admin space of the local session and of remote zenoh nodes.
+
+  Every zenoh node exposes its state under `@/<zid>/<whatami>/**`.
+  The functions of this module query it through a session and decode the replies
+  into structs, instead of returning raw JSON.
+
+  Only nodes reachable from the session, with their admin space enabled and readable,
+  reply within the timeout. Missing replies are not an error.
+
+  > #### Admin space is disabled by default {: .warning}
+  >
+  > A node only exposes `@/<zid>/<whatami>/**` when its `adminspace/enabled` config is `true`,
+  > so without it the local session is missing from the results of `nodes/2`, `entities/3`
+  > and `linkstate/2`. Enable it before opening the session, e.g.
+  > `Zenohex.Config.insert_json5(config, "adminspace/enabled", "true")`.
+
+  see. https://zenoh.io/docs/manual/abstractions/#admin-space
+
+  ## Examples
+
+      iex> {:ok, routers} = Zenohex.Admin.routers(session_id)
+      iex> for %Zenohex.Admin.Node{zid: zid, neighbors: neighbors} <- routers,
+      ...>     %Zenohex.Admin.Neighbor{zid: neighbor_zid} <- neighbors,
+      ...>     do: {zid, neighbor_zid}
+  """
+
+  @type whatami :: :router | :peer | :client
+  @type entity_kind :: :publisher | :querier | :subscriber | :queryable | :token
+
+  defmodule Link do
+    @moduledoc """
+    A link of a neighbor, as seen by the node which reported it.
+    """
+
+    @type t :: %__MODULE__{src: String.t(), dst: String.t()}
+    defstruct [:src, :dst]
+  end
+
+  defmodule Neighbor do
+    @moduledoc """
+    A zenoh node with which a node has a transport.
+
+    `whatami` is `nil` if the node did not report it.
+    """
+
+    @type t :: %__MODULE__{
+            zid: Zenohex.ZenohId.t(),
+            whatami: Zenohex.Admin.whatami() | nil,
+            links: [Zenohex.Admin.Link.t()]
+          }
+    defstruct [:zid, :whatami, links: []]
+  end
+
+  defmodule Node do
+    @moduledoc """
+    A zenoh node, decoded from its `@/<zid>/<whatami>` admin space key.
+    """
+
+    @type t :: %__MODULE__{
+            zid: Zenohex.ZenohId.t(),
+            whatami: Zenohex.Admin.whatami(),
+            version: String.t() | nil,
+            locators: [String.t()],
+            neighbors: [Zenohex.Admin.Neighbor.t()]
+          }
+    defstruct [:zid, :whatami, :version, locators: [], neighbors: []]
+  end
+
+  defmodule Entity do
+    @moduledoc """
+    A key expression declared on a node, decoded from its
+    `@/<zid>/<whatami>/<kind>/<key_expr>` admin space key.
+    """
+
+    @type t :: %__MODULE__{
+            zid: Zenohex.ZenohId.t(),
+            whatami: Zenohex.Admin.whatami(),
+            kind: Zenohex.Admin.entity_kind(),
+            key_expr: String.t()
+          }
+    defstruct [:zid, :whatami, :kind, :key_expr]
+  end
+
+  defmodule Edge do
+    @moduledoc """
+    An edge of a linkstate graph. `weight` is `nil` if the node did not report it.
+    """
+
+    @type t :: %__MODULE__{
+            from: Zenohex.ZenohId.t(),
+            to: Zenohex.ZenohId.t(),
+            weight: float() | nil
+          }
+    defstruct [:from, :to, :weight]
+  end
+
+  defmodule Graph do
+    @moduledoc """
+    A linkstate graph, as seen by the node which reported it,
+    decoded from its `@/<zid>/<whatami>/linkstate/<name>` admin space key.
+
+    Only routers, and peers in linkstate mode, report linkstate graphs.
+    """
+
+    @type t :: %__MODULE__{
+            zid: Zenohex.ZenohId.t(),
+            whatami: Zenohex.Admin.whatami(),
+            name: String.t(),
+            nodes: [Zenohex.ZenohId.t()],
+            edges: [Zenohex.Admin.Edge.t()]
+          }
+    defstruct [:zid, :whatami, :name, nodes: [], edges: []]
+  end
+
+  @doc """
+  Returns the nodes which replied, including the local session
+  if its admin space is enabled.
+  """
+  @spec nodes(Zenohex.Session.id(), non_neg_integer()) ::
+          {:ok, [Node.t()]} | {:error, reason :: term()}
+  defdelegate nodes(session_id, timeout \\ 1000), to: Zenohex.Nif, as: :admin_nodes
+
+  @doc """
+  Returns the routers which replied.
+  """
+  @spec routers(Zenohex.Session.id(), non_neg_integer()) ::
+          {:ok, [Node.t()]} | {:error, reason :: term()}
+  def routers(session_id, timeout \\ 1000), do: nodes_by_whatami(session_id, :router, timeout)
+
+  @doc """
+  Returns the peers which replied.
+  """
+  @spec peers(Zenohex.Session.id(), non_neg_integer()) ::
+          {:ok, [Node.t()]} | {:error, reason :: term()}
+  def peers(session_id, timeout \\ 1000), do: nodes_by_whatami(session_id, :peer, timeout)
+
+  defp nodes_by_whatami(session_id, whatami, timeout) do
+    with {:ok, nodes} <- nodes(session_id, timeout) do
+      {:ok, Enum.filter(nodes, &(&1.whatami == whatami))}
+    end
+  end
+
+  @doc """
+  Returns the entities of the given kind known by each node which replied.
+
+  Routers also report the entities of the clients and peers routed through them.
+  """
+  @spec entities(Zenohex.Session.id(), entity_kind(), non_neg_integer()) ::
+          {:ok, [Entity.t()]} | {:error, reason :: term()}
+  defdelegate entities(session_id, kind, timeout \\ 1000), to: Zenohex.Nif, as: :admin_entities
+
+  @doc """
+  Returns the subscribers known by each node which replied, see `entities/3`.
+  """
+  @spec subscribers(Zenohex.Session.id(), non_neg_integer()) ::
+          {:ok, [Entity.t()]} | {:error, reason :: term()}
+  def subscribers(session_id, timeout \\ 1000), do: entities(session_id, :subscriber, timeout)
+
+  @doc """
+  Returns the queryables known by each node which replied, see `entities/3`.
+  """
+  @spec queryables(Zenohex.Session.id(), non_neg_integer()) ::
+          {:ok, [Entity.t()]} | {:error, reason :: term()}
+  def queryables(session_id, timeout \\ 1000), do: entities(session_id, :queryable, timeout)
+
+  @doc """
+  Returns the linkstate graphs of the nodes which replied.
+  """
+  @spec linkstate(Zenohex.Session.id(), non_neg_integer()) ::
+          {:ok, [Graph.t()]} | {:error, reason :: term()}
+  defdelegate linkstate(session_id, timeout \\ 1000), to: Zenohex.Nif, as: :admin_linkstate
+end
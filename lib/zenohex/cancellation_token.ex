@@ -0,0 +1,47 @@
+defmodule Zenohex.CancellationToken do
+  @moduledoc """
+  A token used to interrupt in-flight queries.
+
+  Pass the token as the `:cancellation_token` option of `Zenohex.Session.get/4`,
+  `Zenohex.Session.get_async/4`, `Zenohex.Querier.get/3`, `Zenohex.Querier.get_async/3`,
+  `Zenohex.Liveliness.get/4` or `Zenohex.Liveliness.get_async/4`, then call `cancel/1`
+  to stop the query and its reply handling.
+
+  A single token can be shared by several queries. Once cancelled,
+  queries started with the token are cancelled immediately.
+
+  see. https://docs.rs/zenoh/latest/zenoh/cancellation/struct.CancellationToken.html
+
+  ## Examples
+
+      iex> token = Zenohex.CancellationToken.new()
+      iex> {:ok, ref} = Zenohex.Session.get_async(session_id, "key/expr", self(), cancellation_token: token)
+      iex> Zenohex.CancellationToken.cancel(token)
+      :ok
+  """
+
+  @type t :: reference()
+
+  @doc """
+  Creates a new cancellation token.
+  """
+  @spec new() :: t()
+  defdelegate new(), to: Zenohex.Nif, as: :cancellation_token_new
+
+  @doc """
+  Interrupts all queries associated with the token.
+
+  Returns after running reply callbacks have finished. Replies of `get_async` functions
+  which are still queued for delivery are dropped, but a reply already being sent
+  while this function runs may still arrive after it returns.
+  `{:zenohex_get_done, ref}` is still sent once the query ends.
+  """
+  @spec cancel(t()) :: :ok | {:error, reason :: term()}
+  defdelegate cancel(token), to: Zenohex.Nif, as: :cancellation_token_cancel
+
+  @doc """
+  Returns `true` if `cancel/1` has been called on the token.
+  """
+  @spec cancelled?(t()) :: boolean()
+  defdelegate cancelled?(token), to: Zenohex.Nif, as: :cancellation_token_cancelled?
+end
@@ -146,37 +146,43 @@ defmodule Zenohex.Config do
   """
   @spec insert_json5(t(), String.t(), String.t() | list()) ::
           {:ok, t()} | {:error, reason :: term()}
-  def insert_json5(config, key, value)
-      when is_binary(config) and is_binary(key) and is_list(value) do
+  def insert_json5(config, key, value) when is_binary(config) do
+    insert_json5_with(&Zenohex.Nif.config_insert_json5(config, &1, &2), key, value)
+  end
+
+  # Shared with `Zenohex.Session.config_insert_json5/3`, which inserts into a running session.
+  @doc false
+  @spec insert_json5_with((String.t(), String.t() -> term()), String.t(), String.t() | list()) ::
+          term()
+  def insert_json5_with(insert, key, value) when is_binary(key) and is_list(value) do
     if value != [] and List.ascii_printable?(value) do
       {:error,
        "charlist is not supported for insert_json5/3. Pass a binary string (\"peer\") or a JSON array list."}
     else
       try do
         encoded_value = value |> JSON.encode_to_iodata!() |> IO.iodata_to_binary()
-        insert_json5(config, key, encoded_value)
+        insert_json5_with(insert, key, encoded_value)
       rescue
         error -> {:error, {:json_encode_failed, error}}
       end
     end
   end
 
-  def insert_json5(config, key, value)
-      when is_binary(config) and is_binary(key) and is_binary(value) do
-    case Zenohex.Nif.config_insert_json5(config, key, value) do
-      {:ok, _updated_config} = result ->
-        result
-
+  def insert_json5_with(insert, key, value) when is_binary(key) and is_binary(value) do
+    case insert.(key, value) do
       {:error, _reason} = original_error ->
         # If the value is not a valid JSON5 format (e.g., `"peer"`),
         # retry by quoting it as a JSON string.
         # If the retry also fails, return the original error.
         quoted_value = JSON.encode!(value)
 
-        case Zenohex.Nif.config_insert_json5(config, key, quoted_value) do
-          {:ok, _updated_config} = result -> result
+        case insert.(key, quoted_value) do
           {:error, _reason} -> original_error
+          result -> result
         end
+
+      result ->
+        result
     end
   end
 end
@@ -0,0 +1,58 @@
+defmodule Zenohex.Connectivity do
+  @moduledoc """
+  Interface for listening to connectivity events of a session.
+
+  A connectivity listener notifies a process when transports to remote zenoh nodes
+  (routers, peers or clients) are opened or closed, and when the session itself is closed.
+
+  The following messages are sent to the process:
+
+  - `{:zenohex_transport, :opened, t:Zenohex.Session.Transport.t/0}` when a transport is opened.
+  - `{:zenohex_transport, :closed, t:Zenohex.Session.Transport.t/0}` when a transport is closed.
+    `links` are the links seen when the transport was opened.
+  - `{:zenohex_session, :closed, t:session_closed/0}` when the session is closed,
+    either by `Zenohex.Session.close/1` (`reason: :close`) or because its id was
+    garbage-collected (`reason: :drop`). No more messages follow.
+
+  see. https://docs.rs/zenoh/latest/zenoh/session/struct.SessionInfo.html#method.transport_events_listener
+
+  ## Examples
+
+      iex> {:ok, listener_id} =
+      ...>   Zenohex.Connectivity.declare_listener(session_id, self(), history: true)
+      iex> flush()
+      {:zenohex_transport, :opened, %Zenohex.Session.Transport{whatami: :router, ...}}
+  """
+
+  @type listener_id :: reference()
+
+  @type session_closed :: %{zid: Zenohex.ZenohId.t(), reason: :close | :drop}
+
+  @type declare_listener_opts :: [history: boolean()]
+
+  @doc """
+  Declares a connectivity listener for the session.
+
+  With `history: true`, `:opened` messages are also sent for the transports
+  which are already open.
+
+  > ### Important {: .info}
+  >
+  > The returned `listener_id` must be held for as long as the listener is in use.
+  > If it is not held and gets garbage-collected by the BEAM,
+  > the underlying listener in Rust will be automatically dropped.
+  """
+  @spec declare_listener(Zenohex.Session.id(), pid(), declare_listener_opts()) ::
+          {:ok, listener_id()} | {:error, reason :: term()}
+  defdelegate declare_listener(session_id, pid \\ self(), opts \\ []),
+    to: Zenohex.Nif,
+    as: :connectivity_declare_listener
+
+  @doc """
+  Undeclares the connectivity listener identified by the given ID.
+  """
+  @spec undeclare_listener(listener_id()) :: :ok | {:error, reason :: term()}
+  defdelegate undeclare_listener(listener_id),
+    to: Zenohex.Nif,
+    as: :connectivity_undeclare_listener
+end
@@ -0,0 +1,50 @@
+defmodule Zenohex.EntityGlobalId do
+  @moduledoc """
+  A struct that corresponds one-to-one to `zenoh::session::EntityGlobalId`.
+
+  Identifies an entity (publisher, subscriber, queryable, querier, ...) across the whole
+  Zenoh network.
+
+  - `zid` : Zenoh ID of the session which declared the entity.
+  - `eid` : Entity ID of the entity within its session.
+
+  Used as `source_id` of `Zenohex.SourceInfo` and `source` of `Zenohex.SampleMiss`,
+  so that a received sample can be matched against a known publisher.
+
+  see. https://docs.rs/zenoh/latest/zenoh/session/struct.EntityGlobalId.html
+
+  ## Examples
+
+      iex> {:ok, publisher_id} = Zenohex.Session.declare_publisher(session_id, "key/expr")
+      iex> Zenohex.EntityGlobalId.from_entity(publisher_id)
+      %Zenohex.EntityGlobalId{zid: "208a2ec783ec4527a39cc1d5559c70e9", eid: 1}
+  """
+
+  @type t :: %__MODULE__{
+          zid: Zenohex.ZenohId.t(),
+          eid: non_neg_integer()
+        }
+  @enforce_keys [:zid, :eid]
+  defstruct [:zid, :eid]
+
+  @doc """
+  Returns the global ID of a declared entity.
+
+  Accepts any entity id returned by the `declare_*` functions of `Zenohex.Session`
+  and `Zenohex.Liveliness`.
+  """
+  @spec from_entity(Zenohex.Nif.entity_id()) :: t()
+  defdelegate from_entity(entity_id), to: Zenohex.Nif, as: :entity_global_id_from_entity
+
+  @doc """
+  Returns the Zenoh ID of the session which declared the entity.
+  """
+  @spec zid(t()) :: Zenohex.ZenohId.t()
+  def zid(%__MODULE__{zid: zid}), do: zid
+
+  @doc """
+  Returns the entity ID of the entity within its session.
+  """
+  @spec eid(t()) :: non_neg_integer()
+  def eid(%__MODULE__{eid: eid}), do: eid
+end
@@ -4,7 +4,50 @@ defmodule Zenohex.KeyExpr do
 
   Key expressions in Zenoh define paths or patterns used to match resources.
   These utilities help manage their correctness and relationships.
+
+  Functions returning a boolean, a list or a relation raise `ArgumentError`
+  if given an invalid key expression.
+  """
+
+  @typedoc """
+  A key expression declared with `Zenohex.Session.declare_keyexpr/2`.
+  """
+  @type declared :: reference()
+
+  @typedoc """
+  A key expression, as accepted by the functions publishing, querying or declaring entities.
   """
+  @type t :: String.t() | declared()
+
+  @type relation :: :disjoint | :intersects | :includes | :equals
+
+  @type invalid_reason ::
+          :empty_chunk
+          | :star_in_chunk
+          | :single_star_after_double_star
+          | :double_star_after_double_star
+          | :lone_dollar_star
+          | :dollar_after_dollar
+          | :unbound_dollar
+          | :forbidden_character
+          | :other
+
+  defmodule ValidationError do
+    @moduledoc """
+    Why a key expression is invalid, as returned by `Zenohex.KeyExpr.validate/1`.
+
+    `position` is the byte offset of the offending chunk or character.
+    It is `nil` when `reason` is `:other`, for a key expression zenoh rejects
+    without breaking any of the other rules, and `message` then carries the error of zenoh.
+    """
+
+    @type t :: %__MODULE__{
+            reason: Zenohex.KeyExpr.invalid_reason(),
+            position: non_neg_integer() | nil,
+            message: String.t()
+          }
+    defstruct [:reason, :position, :message]
+  end
 
   @doc """
   Canonizes the `key_expr`.
@@ -61,4 +104,133 @@ defmodule Zenohex.KeyExpr do
   defdelegate join(key_expr1, key_expr2),
     to: Zenohex.Nif,
     as: :keyexpr_join
+
+  @doc """
+  Checks that `key_expr` is valid and canon, without changing it.
+
+  Returns `{:error, {:not_canon, canonized}}` if canonization would change it,
+  with the key expression `canonize/1` would return.
+
+  ## Examples
+
+      iex> Zenohex.KeyExpr.canonize_strict("key/expr/*/**")
+      {:ok, "key/expr/*/**"}
+      iex> Zenohex.KeyExpr.canonize_strict("key/expr/**/*")
+      {:error, {:not_canon, "key/expr/*/**"}}
+  """
+  @spec canonize_strict(String.t()) ::
+          {:ok, String.t()} | {:error, {:not_canon, String.t()}} | {:error, String.t()}
+  defdelegate canonize_strict(key_expr),
+    to: Zenohex.Nif,
+    as: :keyexpr_canonize
+
+  @doc """
+  Validates the `key_expr`, returning why and where it is invalid.
+
+  ## Examples
+
+      iex> Zenohex.KeyExpr.validate("key/expr")
+      :ok
+      iex> Zenohex.KeyExpr.validate("key/ex*pr")
+      {:error, %Zenohex.KeyExpr.ValidationError{reason: :star_in_chunk, position: 6, ...}}
+  """
+  @spec validate(String.t()) :: :ok | {:error, ValidationError.t()}
+  defdelegate validate(key_expr),
+    to: Zenohex.Nif,
+    as: :keyexpr_validate
+
+  @doc """
+  Returns the relation of `key_expr1` to `key_expr2`.
+
+  `:includes` means that `key_expr1` includes `key_expr2`.
+
+  ## Examples
+
+      iex> Zenohex.KeyExpr.relation_to("key/**", "key/expr")
+      :includes
+  """
+  @spec relation_to(String.t(), String.t()) :: relation()
+  defdelegate relation_to(key_expr1, key_expr2),
+    to: Zenohex.Nif,
+    as: :keyexpr_relation_to
+
+  @doc """
+  Concatenates `key_expr` and `suffix` as strings, without inserting a `/`.
+
+  Returns an error if the result is not a canon key expression,
+  or if `key_expr` ends with `*` and `suffix` starts with `*`.
+  Prefer `join/2` when concatenating chunks.
+
+  ## Examples
+
+      iex> Zenohex.KeyExpr.concat("key/expr", "_suffix")
+      {:ok, "key/expr_suffix"}
+  """
+  @spec concat(String.t(), String.t()) :: {:ok, String.t()} | {:error, String.t()}
+  defdelegate concat(key_expr, suffix),
+    to: Zenohex.Nif,
+    as: :keyexpr_concat
+
+  @doc """
+  Splits the `key_expr` into its chunks.
+
+  ## Examples
+
+      iex> Zenohex.KeyExpr.chunks("key/*/expr")
+      ["key", "*", "expr"]
+  """
+  @spec chunks(String.t()) :: [String.t()]
+  defdelegate chunks(key_expr),
+    to: Zenohex.Nif,
+    as: :keyexpr_chunks
+
+  @doc """
+  Returns true if `key_expr` contains a wildcard, `*`, `**` or `$*`.
+  """
+  @spec wild?(String.t()) :: boolean()
+  defdelegate wild?(key_expr),
+    to: Zenohex.Nif,
+    as: :keyexpr_wild?
+
+  @doc """
+  Removes `prefix` from the beginning of `key_expr`.
+
+  Returns every possible suffix, since a wildcard may match `prefix` in several ways,
+  or `[]` if `prefix` cannot match the beginning of `key_expr`.
+
+  ## Examples
+
+      iex> Zenohex.KeyExpr.strip_prefix("a/**/c/*", "a/b/c")
+      ["*", "**/c/*"]
+  """
+  @spec strip_prefix(String.t(), String.t()) :: [String.t()]
+  defdelegate strip_prefix(key_expr, prefix),
+    to: Zenohex.Nif,
+    as: :keyexpr_strip_prefix
+
+  @doc """
+  Removes the non-wild `prefix` from the beginning of `key_expr`.
+
+  Like `strip_prefix/2`, but returns only the longest suffix, or `nil`.
+  Raises `ArgumentError` if `prefix` contains a wildcard.
+
+  ## Examples
+
+      iex> Zenohex.KeyExpr.strip_nonwild_prefix("demo/**/test", "demo/example")
+      "**/test"
+  """
+  @spec strip_nonwild_prefix(String.t(), String.t()) :: String.t() | nil
+  defdelegate strip_nonwild_prefix(key_expr, prefix),
+    to: Zenohex.Nif,
+    as: :keyexpr_strip_nonwild_prefix
+
+  @doc """
+  Undeclares a key expression declared with `Zenohex.Session.declare_keyexpr/2`.
+
+  It can still be used afterwards, as a plain key expression.
+  """
+  @spec undeclare(declared()) :: :ok | {:error, reason :: term()}
+  defdelegate undeclare(key_expr),
+    to: Zenohex.Nif,
+    as: :keyexpr_undeclare
 end
@@ -0,0 +1,55 @@
+defmodule Zenohex.KeyExpr.Format do
+  @moduledoc """
+  Key expression formats, backed by zenoh's KeFormat, for building and parsing keys.
+
+  A format is a key expression whose chunks may be specs,
+  written `${id:pattern}` or `${id:pattern#default}`.
+  `pattern` is a key expression restricting the values of the spec,
+  and `default` is used when formatting without a value for `id`.
+
+  Formatting checks each value against its pattern, so a malformed key cannot be built.
+  Parsing assigns the chunks of a key expression to the specs, greedily from left to right.
+
+  see. https://docs.rs/zenoh/latest/zenoh/key_expr/format/index.html
+
+  ## Examples
+
+      iex> {:ok, format} = Zenohex.KeyExpr.Format.new("fleet/${robot:*}/sensor/${kind:*}")
+      iex> Zenohex.KeyExpr.Format.format(format, robot: "r1", kind: "lidar")
+      {:ok, "fleet/r1/sensor/lidar"}
+      iex> Zenohex.KeyExpr.Format.parse(format, "fleet/r2/sensor/imu")
+      {:ok, %{"robot" => "r2", "kind" => "imu"}}
+  """
+
+  @type t :: reference()
+
+  @doc """
+  Compiles `format`.
+  """
+  @spec new(String.t()) :: {:ok, t()} | {:error, reason :: term()}
+  defdelegate new(format), to: Zenohex.Nif, as: :keyexpr_format_new
+
+  @doc """
+  Builds a key expression from the values of the specs of `format`.
+
+  Keys and values of `values` are converted with `to_string/1`.
+  An error is returned if an id is not a spec of `format`,
+  if a value is not included by the pattern of its spec,
+  or if a spec without default has no value.
+  """
+  @spec format(t(), Enumerable.t({atom() | String.t(), String.Chars.t()})) ::
+          {:ok, String.t()} | {:error, reason :: term()}
+  def format(format, values) do
+    values = Map.new(values, fn {id, value} -> {to_string(id), to_string(value)} end)
+    Zenohex.Nif.keyexpr_format_build(format, values)
+  end
+
+  @doc """
+  Parses `key_expr` into a map of the values of the specs of `format`.
+
+  A spec whose pattern is `**` may match no chunk, its value is then `""`.
+  An error is returned if `key_expr` does not intersect `format`.
+  """
+  @spec parse(t(), String.t()) :: {:ok, %{String.t() => String.t()}} | {:error, reason :: term()}
+  defdelegate parse(format, key_expr), to: Zenohex.Nif, as: :keyexpr_format_parse
+end
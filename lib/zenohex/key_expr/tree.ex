@@ -0,0 +1,62 @@
+defmodule Zenohex.KeyExpr.Tree do
+  @moduledoc """
+  A tree of key expressions mapped to terms, backed by zenoh's KeTree.
+
+  Matching one key against many patterns with `Zenohex.KeyExpr.intersects?/2`
+  or `Zenohex.KeyExpr.includes?/2` takes one NIF call per pattern.
+  A tree answers it in a single call, returning the terms attached to the matching patterns.
+
+  Each key expression is mapped to one term. Inserting it again replaces the term.
+  The tree is mutable and shared by every process holding it.
+
+  Functions raise `ArgumentError` if given an invalid key expression.
+
+  ## Examples
+
+      iex> tree = Zenohex.KeyExpr.Tree.new()
+      iex> :ok = Zenohex.KeyExpr.Tree.insert(tree, "robot/*/pose", :pose)
+      iex> :ok = Zenohex.KeyExpr.Tree.insert(tree, "robot/**", :robot)
+      iex> Zenohex.KeyExpr.Tree.including(tree, "robot/1/pose") |> Enum.sort()
+      [:pose, :robot]
+  """
+
+  @type t :: reference()
+
+  @doc """
+  Creates an empty tree.
+  """
+  @spec new() :: t()
+  defdelegate new(), to: Zenohex.Nif, as: :keyexpr_tree_new
+
+  @doc """
+  Maps `key_expr` to `term`, replacing the previous term if any.
+  """
+  @spec insert(t(), String.t(), term()) :: :ok
+  defdelegate insert(tree, key_expr, term), to: Zenohex.Nif, as: :keyexpr_tree_insert
+
+  @doc """
+  Removes `key_expr` from the tree.
+  """
+  @spec remove(t(), String.t()) :: :ok | {:error, :not_found}
+  defdelegate remove(tree, key_expr), to: Zenohex.Nif, as: :keyexpr_tree_remove
+
+  @doc """
+  Returns the terms of the key expressions intersecting `key_expr`.
+  """
+  @spec intersecting(t(), String.t()) :: [term()]
+  defdelegate intersecting(tree, key_expr), to: Zenohex.Nif, as: :keyexpr_tree_intersecting
+
+  @doc """
+  Returns the terms of the key expressions including `key_expr`.
+
+  This is the usual lookup for routing a key to the patterns it matches.
+  """
+  @spec including(t(), String.t()) :: [term()]
+  defdelegate including(tree, key_expr), to: Zenohex.Nif, as: :keyexpr_tree_including
+
+  @doc """
+  Returns the terms of the key expressions included in `key_expr`.
+  """
+  @spec included(t(), String.t()) :: [term()]
+  defdelegate included(tree, key_expr), to: Zenohex.Nif, as: :keyexpr_tree_included
+end
@@ -9,6 +9,7 @@ defmodule Zenohex.Liveliness do
   @type token :: reference()
 
   @type get_opts :: [
+          cancellation_token: Zenohex.CancellationToken.t(),
           query_timeout: non_neg_integer()
         ]
 
@@ -28,12 +29,42 @@ defmodule Zenohex.Liveliness do
       iex> Zenohex.Liveliness.get(session_id, "key/expr", 100)
       {:ok, [%Zenohex.Sample{}]}
   """
-  @spec get(Zenohex.Session.id(), String.t(), non_neg_integer(), get_opts()) ::
+  @spec get(Zenohex.Session.id(), Zenohex.KeyExpr.t(), non_neg_integer(), get_opts()) ::
           {:ok, [Zenohex.Sample.t() | Zenohex.Query.ReplyError.t()]}
           | {:error, :timeout}
           | {:error, reason :: term()}
   defdelegate get(session_id, key_expr, timeout, opts \\ []), to: Zenohex.Nif, as: :liveliness_get
 
+  @doc """
+  Query liveliness tokens with matching key expressions asynchronously.
+
+  Replies are delivered to `pid` as `t:Zenohex.Session.get_async_message/0` messages,
+  tagged with the returned reference, followed by `{:zenohex_get_done, ref}`.
+
+  The `:ref` option sets the reference used to tag replies. Defaults to `make_ref/0`.
+
+  ## Examples
+
+      iex> {:ok, ref} = Zenohex.Liveliness.get_async(session_id, "key/expr")
+      iex> receive do
+      ...>   {:zenohex_get_done, ^ref} -> :done
+      ...> end
+  """
+  @spec get_async(
+          Zenohex.Session.id(),
+          Zenohex.KeyExpr.t(),
+          pid(),
+          get_opts() | Zenohex.Session.get_async_opts()
+        ) ::
+          {:ok, ref :: term()} | {:error, reason :: term()}
+  def get_async(session_id, key_expr, pid \\ self(), opts \\ []) do
+    {ref, opts} = Keyword.pop_lazy(opts, :ref, &make_ref/0)
+
+    with :ok <- Zenohex.Nif.liveliness_get_async(session_id, key_expr, pid, ref, opts) do
+      {:ok, ref}
+    end
+  end
+
   @doc """
   Create a Subscriber for liveliness changes matching the given key expression.
 
@@ -49,7 +80,12 @@ defmodule Zenohex.Liveliness do
       iex> Zenohex.Liveliness.declare_subscriber(session_id, "key/expr")
       {:ok, #Reference<...>}
   """
-  @spec declare_subscriber(Zenohex.Session.id(), String.t(), pid(), declare_subscriber_opts()) ::
+  @spec declare_subscriber(
+          Zenohex.Session.id(),
+          Zenohex.KeyExpr.t(),
+          pid(),
+          declare_subscriber_opts()
+        ) ::
           {:ok, subscriber_id :: Zenohex.Subscriber.id()} | {:error, reason :: term()}
   defdelegate declare_subscriber(session_id, key_expr, pid \\ self(), opts \\ []),
     to: Zenohex.Nif,
@@ -85,7 +121,7 @@ defmodule Zenohex.Liveliness do
       iex> Zenohex.Liveliness.declare_token(session_id, "key/expr")
       {:ok, #Reference<...>}
   """
-  @spec declare_token(Zenohex.Session.id(), String.t()) ::
+  @spec declare_token(Zenohex.Session.id(), Zenohex.KeyExpr.t()) ::
           {:ok, token()} | {:error, reason :: term()}
   defdelegate declare_token(session_id, key_expr),
     to: Zenohex.Nif,
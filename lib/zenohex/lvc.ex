@@ -0,0 +1,74 @@
+defmodule Zenohex.Lvc do
+  @moduledoc """
+  Interface for last-value caches, which keep the latest sample per key inside the NIF.
+
+  A cache subscribes to a key expression like a `Zenohex.Subscriber`,
+  but its samples are not sent to any process.
+  They are read synchronously with `get/2`, `match/2` and `keys/1` instead.
+  Like `Zenohex.KeyExpr.Tree`, `get/2` and `match/2` raise `ArgumentError`
+  if given an invalid key expression.
+  This suits high-rate topics whose latest values are only read occasionally.
+
+  Samples are ordered by their timestamp, when they have one.
+  A sample older than the cached one for the same key is ignored,
+  and a delete sample removes the key.
+  A timestamped delete is kept as a tombstone for `:tombstone_lifespan`,
+  so that an older put received late does not bring the key back.
+
+  ## Examples
+
+      iex> {:ok, lvc} = Zenohex.Lvc.declare(session_id, "robot/*/pose")
+      iex> :ok = Zenohex.Session.put(session_id, "robot/1/pose", "1,2")
+      iex> Zenohex.Lvc.get(lvc, "robot/1/pose")
+      {:ok, %Zenohex.Sample{key_expr: "robot/1/pose", payload: "1,2"}}
+      iex> Zenohex.Lvc.keys(lvc)
+      {:ok, ["robot/1/pose"]}
+  """
+
+  @type lvc :: reference()
+
+  @type declare_opts :: [
+          allowed_origin: Zenohex.Session.locality(),
+          tombstone_lifespan: non_neg_integer()
+        ]
+
+  @doc """
+  Declares a last-value cache for `key_expr`.
+
+  `:tombstone_lifespan` is in milliseconds, and defaults to one day.
+
+  > ### Important {: .info}
+  >
+  > The returned `lvc` must be held for as long as the cache is in use.
+  > If it is not held and gets garbage-collected by the BEAM,
+  > the underlying subscriber in Rust will be automatically undeclared.
+  """
+  @spec declare(Zenohex.Session.id(), Zenohex.KeyExpr.t(), declare_opts()) ::
+          {:ok, lvc()} | {:error, reason :: term()}
+  defdelegate declare(session_id, key_expr, opts \\ []), to: Zenohex.Nif, as: :lvc_declare
+
+  @doc """
+  Undeclares the subscriber of the cache. Its contents are kept, and can still be read.
+  """
+  @spec undeclare(lvc()) :: :ok | {:error, reason :: term()}
+  defdelegate undeclare(lvc), to: Zenohex.Nif, as: :lvc_undeclare
+
+  @doc """
+  Returns the latest sample of `key`, or `{:error, :not_found}`.
+  """
+  @spec get(lvc(), String.t()) ::
+          {:ok, Zenohex.Sample.t()} | {:error, :not_found} | {:error, reason :: term()}
+  defdelegate get(lvc, key), to: Zenohex.Nif, as: :lvc_get
+
+  @doc """
+  Returns the latest samples whose key intersects `key_expr`.
+  """
+  @spec match(lvc(), String.t()) :: {:ok, [Zenohex.Sample.t()]} | {:error, reason :: term()}
+  defdelegate match(lvc, key_expr), to: Zenohex.Nif, as: :lvc_match
+
+  @doc """
+  Returns the keys of the cached samples.
+  """
+  @spec keys(lvc()) :: {:ok, [String.t()]} | {:error, reason :: term()}
+  defdelegate keys(lvc), to: Zenohex.Nif, as: :lvc_keys
+end
@@ -4,9 +4,18 @@ defmodule Zenohex.Nif do
   @type session_id :: reference()
   @type entity_id :: reference()
   @type matching_listener :: reference()
+  @type connectivity_listener :: reference()
+  @type storage :: reference()
+  @type lvc :: reference()
+  @type keyexpr_tree :: reference()
+  @type keyexpr_format :: reference()
   @type query :: reference()
   @type scout :: reference()
   @type liveliness_token :: reference()
+  @type receiver :: reference()
+  @type cancellation_token :: reference()
+  @type shm_provider :: reference()
+  @type shm_buffer :: reference()
   @type nif_logger_level :: :debug | :info | :warning | :error
 
   mix_config = Mix.Project.config()
@@ -23,6 +32,9 @@ defmodule Zenohex.Nif do
     # NOTE: Uncomment during zenohhex_nif development.
     #       Setting `mode: :debug` makes `cargo build` skip the `--release` flag.
     # mode: :debug,
+    # NOTE: Cargo features of zenohex_nif, e.g. statically linked plugins.
+    #       see. Zenohex.Plugins
+    features: Application.compile_env(:zenohex, :nif_features, []),
     # NOTE: FROM HERE RustlerPrecompiled opts
     version: version,
     base_url: "#{github_url}/releases/download/v#{version}",
@@ -43,60 +55,119 @@ defmodule Zenohex.Nif do
   @spec session_close(session_id()) :: :ok | {:error, reason :: term()}
   def session_close(_session_id), do: err()
 
-  @spec session_put(session_id(), String.t(), binary(), keyword()) ::
+  @spec session_put(session_id(), Zenohex.KeyExpr.t(), binary() | shm_buffer(), keyword()) ::
           :ok | {:error, reason :: term()}
   def session_put(_session_id, _key_expr, _payload, _opts), do: err()
 
-  @spec session_delete(session_id(), String.t(), keyword()) :: :ok | {:error, reason :: term()}
+  @spec session_delete(session_id(), Zenohex.KeyExpr.t(), keyword()) ::
+          :ok | {:error, reason :: term()}
   def session_delete(_session_id, _key_expr, _opts), do: err()
 
-  @spec session_get(session_id(), String.t(), non_neg_integer(), keyword()) ::
+  @spec session_get(session_id(), Zenohex.KeyExpr.t(), non_neg_integer(), keyword()) ::
           {:ok, [Zenohex.Sample.t() | Zenohex.Query.ReplyError.t()]}
           | {:error, :timeout}
           | {:error, reason :: term()}
   def session_get(_session_id, _selector, _timeout, _opts), do: err()
 
-  # NOTE: Not supported in Zenohex.
-  #       Use `session_get/4` for one-shot queries or
-  #       `querier_get_async/3` with a declared querier.
-  # @spec session_get_async(session_id(), String.t(), pid(), keyword()) ::
-  #         :ok | {:error, reason :: term()}
-  # def session_get_async(_session_id, _selector, _pid, _opts), do: err()
+  @spec session_get_async(session_id(), Zenohex.KeyExpr.t(), pid(), term(), keyword()) ::
+          :ok | {:error, reason :: term()}
+  def session_get_async(_session_id, _selector, _pid, _ref, _opts), do: err()
+
+  @spec session_get_pull(session_id(), Zenohex.KeyExpr.t(), Zenohex.Receiver.handler(), keyword()) ::
+          {:ok, receiver()} | {:error, reason :: term()}
+  def session_get_pull(_session_id, _selector, _handler, _opts), do: err()
 
-  @spec session_new_timestamp(session_id()) :: {:ok, String.t()} | {:error, reason :: term()}
+  @spec session_new_timestamp(session_id()) ::
+          {:ok, Zenohex.Timestamp.t()} | {:error, reason :: term()}
   def session_new_timestamp(_session_id), do: err()
 
+  @spec session_update_with_timestamp(session_id(), Zenohex.Timestamp.t()) ::
+          :ok | {:error, reason :: term()}
+  def session_update_with_timestamp(_session_id, _timestamp), do: err()
+
   @spec session_info(session_id()) :: {:ok, Zenohex.Session.Info.t()} | {:error, reason :: term()}
   def session_info(_session_id), do: err()
 
-  # NOTE: Not supported in Zenohex.
-  #       Use publisher instead, which is sufficient for all use cases.
-  # @spec session_declare_keyexpr(id(), String.t()) ::
-  #         {:ok, reference()} | {:error, reason :: term()}
-  # def session_declare_keyexpr(_session_id, _key_expr), do: err()
+  @spec session_config(session_id()) :: {:ok, Zenohex.Config.t()} | {:error, reason :: term()}
+  def session_config(_session_id), do: err()
+
+  @spec session_config_get_json(session_id(), String.t()) ::
+          {:ok, String.t()} | {:error, reason :: term()}
+  def session_config_get_json(_session_id, _key), do: err()
+
+  @spec session_config_insert_json5(session_id(), String.t(), String.t()) ::
+          :ok | {:error, reason :: term()}
+  def session_config_insert_json5(_session_id, _key, _value), do: err()
+
+  @spec session_connect(session_id(), String.t()) :: :ok | {:error, reason :: term()}
+  def session_connect(_session_id, _endpoint), do: err()
+
+  @spec session_entities(session_id()) ::
+          {:ok, [Zenohex.Session.Entity.t()]} | {:error, reason :: term()}
+  def session_entities(_session_id), do: err()
+
+  @spec session_undeclare_all(session_id(), keyword()) ::
+          {:ok, non_neg_integer()} | {:error, reason :: term()}
+  def session_undeclare_all(_session_id, _opts), do: err()
+
+  @spec session_declare_keyexpr(session_id(), String.t()) ::
+          {:ok, Zenohex.KeyExpr.declared()} | {:error, reason :: term()}
+  def session_declare_keyexpr(_session_id, _key_expr), do: err()
 
-  @spec session_declare_publisher(session_id(), String.t(), keyword()) ::
+  @spec session_declare_publisher(session_id(), Zenohex.KeyExpr.t(), keyword()) ::
           {:ok, entity_id()} | {:error, reason :: term()}
   def session_declare_publisher(_session_id, _key_expr, _opts), do: err()
 
-  @spec session_declare_querier(session_id(), String.t(), keyword()) ::
+  @spec session_declare_querier(session_id(), Zenohex.KeyExpr.t(), keyword()) ::
           {:ok, entity_id()} | {:error, reason :: term()}
   def session_declare_querier(_session_id, _key_expr, _opts), do: err()
 
-  @spec session_declare_subscriber(session_id(), String.t(), pid(), keyword()) ::
+  @spec session_declare_subscriber(session_id(), Zenohex.KeyExpr.t(), pid(), keyword()) ::
           {:ok, entity_id()} | {:error, reason :: term()}
   def session_declare_subscriber(_session_id, _key_expr, _pid, _opts), do: err()
 
-  @spec session_declare_queryable(session_id(), String.t(), pid(), keyword()) ::
+  @spec session_declare_queryable(session_id(), Zenohex.KeyExpr.t(), pid(), keyword()) ::
           {:ok, entity_id()} | {:error, reason :: term()}
   def session_declare_queryable(_session_id, _key_expr, _pid, _opts), do: err()
 
+  @spec session_declare_pull_subscriber(
+          session_id(),
+          Zenohex.KeyExpr.t(),
+          Zenohex.Receiver.handler(),
+          keyword()
+        ) ::
+          {:ok, entity_id()} | {:error, reason :: term()}
+  def session_declare_pull_subscriber(_session_id, _key_expr, _handler, _opts), do: err()
+
+  @spec session_declare_pull_queryable(
+          session_id(),
+          Zenohex.KeyExpr.t(),
+          Zenohex.Receiver.handler(),
+          keyword()
+        ) ::
+          {:ok, entity_id()} | {:error, reason :: term()}
+  def session_declare_pull_queryable(_session_id, _key_expr, _handler, _opts), do: err()
+
+  @spec session_declare_advanced_publisher(session_id(), Zenohex.KeyExpr.t(), keyword()) ::
+          {:ok, entity_id()} | {:error, reason :: term()}
+  def session_declare_advanced_publisher(_session_id, _key_expr, _opts), do: err()
+
+  @spec session_declare_advanced_subscriber(
+          session_id(),
+          Zenohex.KeyExpr.t(),
+          pid(),
+          keyword()
+        ) ::
+          {:ok, entity_id()} | {:error, reason :: term()}
+  def session_declare_advanced_subscriber(_session_id, _key_expr, _pid, _opts), do: err()
+
   # Publisher
 
   @spec publisher_undeclare(entity_id()) :: :ok | {:error, reason :: term()}
   def publisher_undeclare(_publisher_id), do: err()
 
-  @spec publisher_put(entity_id(), binary(), keyword()) :: :ok | {:error, reason :: term()}
+  @spec publisher_put(entity_id(), binary() | shm_buffer(), keyword()) ::
+          :ok | {:error, reason :: term()}
   def publisher_put(_publisher_id, _payload, _opts), do: err()
 
   @spec publisher_delete(entity_id(), keyword()) :: :ok | {:error, reason :: term()}
@@ -110,9 +181,13 @@ defmodule Zenohex.Nif do
           | {:error, reason :: term()}
   def querier_get(_querier_id, _timeout, _opts), do: err()
 
-  @spec querier_get_async(entity_id(), pid(), keyword()) ::
+  @spec querier_get_async(entity_id(), pid(), term(), keyword()) ::
           :ok | {:error, reason :: term()}
-  def querier_get_async(_querier_id, _pid, _opts), do: err()
+  def querier_get_async(_querier_id, _pid, _ref, _opts), do: err()
+
+  @spec querier_get_pull(entity_id(), Zenohex.Receiver.handler(), keyword()) ::
+          {:ok, receiver()} | {:error, reason :: term()}
+  def querier_get_pull(_querier_id, _handler, _opts), do: err()
 
   @spec querier_undeclare(entity_id()) :: :ok | {:error, reason :: term()}
   def querier_undeclare(_querier_id), do: err()
@@ -130,6 +205,87 @@ defmodule Zenohex.Nif do
   @spec matching_undeclare_listener(matching_listener()) :: :ok | {:error, reason :: term()}
   def matching_undeclare_listener(_matching_listener), do: err()
 
+  # Admin
+
+  @spec admin_nodes(session_id(), non_neg_integer()) ::
+          {:ok, [Zenohex.Admin.Node.t()]} | {:error, reason :: term()}
+  def admin_nodes(_session_id, _timeout), do: err()
+
+  @spec admin_entities(session_id(), Zenohex.Admin.entity_kind(), non_neg_integer()) ::
+          {:ok, [Zenohex.Admin.Entity.t()]} | {:error, reason :: term()}
+  def admin_entities(_session_id, _kind, _timeout), do: err()
+
+  @spec admin_linkstate(session_id(), non_neg_integer()) ::
+          {:ok, [Zenohex.Admin.Graph.t()]} | {:error, reason :: term()}
+  def admin_linkstate(_session_id, _timeout), do: err()
+
+  # Connectivity
+
+  @spec connectivity_declare_listener(session_id(), pid(), keyword()) ::
+          {:ok, connectivity_listener()} | {:error, reason :: term()}
+  def connectivity_declare_listener(_session_id, _pid, _opts), do: err()
+
+  @spec connectivity_undeclare_listener(connectivity_listener()) ::
+          :ok | {:error, reason :: term()}
+  def connectivity_undeclare_listener(_connectivity_listener), do: err()
+
+  # Plugins
+
+  @spec plugins_list(session_id()) ::
+          {:ok, [Zenohex.Plugins.Plugin.t()]} | {:error, reason :: term()}
+  def plugins_list(_session_id), do: err()
+
+  @spec plugins_start(session_id(), String.t(), keyword()) :: :ok | {:error, reason :: term()}
+  def plugins_start(_session_id, _id, _opts), do: err()
+
+  @spec plugins_stop(session_id(), String.t()) :: :ok | {:error, reason :: term()}
+  def plugins_stop(_session_id, _id), do: err()
+
+  @spec plugins_reload(session_id(), String.t(), keyword()) :: :ok | {:error, reason :: term()}
+  def plugins_reload(_session_id, _id, _opts), do: err()
+
+  @spec plugins_status(session_id(), String.t()) ::
+          {:ok, [{String.t(), String.t()}]} | {:error, reason :: term()}
+  def plugins_status(_session_id, _id), do: err()
+
+  # Storage
+
+  @spec storage_declare(session_id(), Zenohex.KeyExpr.t(), keyword()) ::
+          {:ok, storage()} | {:error, reason :: term()}
+  def storage_declare(_session_id, _key_expr, _opts), do: err()
+
+  @spec storage_undeclare(storage()) :: :ok | {:error, reason :: term()}
+  def storage_undeclare(_storage), do: err()
+
+  @spec storage_get(storage(), String.t()) ::
+          {:ok, [Zenohex.Sample.t()]} | {:error, reason :: term()}
+  def storage_get(_storage, _key_expr), do: err()
+
+  @spec storage_keys(storage()) :: {:ok, [String.t()]} | {:error, reason :: term()}
+  def storage_keys(_storage), do: err()
+
+  @spec storage_purge(storage(), String.t()) ::
+          {:ok, non_neg_integer()} | {:error, reason :: term()}
+  def storage_purge(_storage, _key_expr), do: err()
+
+  # Lvc
+
+  @spec lvc_declare(session_id(), Zenohex.KeyExpr.t(), keyword()) ::
+          {:ok, lvc()} | {:error, reason :: term()}
+  def lvc_declare(_session_id, _key_expr, _opts), do: err()
+
+  @spec lvc_undeclare(lvc()) :: :ok | {:error, reason :: term()}
+  def lvc_undeclare(_lvc), do: err()
+
+  @spec lvc_get(lvc(), String.t()) :: {:ok, Zenohex.Sample.t()} | {:error, reason :: term()}
+  def lvc_get(_lvc, _key), do: err()
+
+  @spec lvc_match(lvc(), String.t()) :: {:ok, [Zenohex.Sample.t()]} | {:error, reason :: term()}
+  def lvc_match(_lvc, _key_expr), do: err()
+
+  @spec lvc_keys(lvc()) :: {:ok, [String.t()]} | {:error, reason :: term()}
+  def lvc_keys(_lvc), do: err()
+
   # Subscriber
 
   @spec subscriber_undeclare(entity_id()) :: :ok | {:error, reason :: term()}
@@ -140,9 +296,93 @@ defmodule Zenohex.Nif do
   @spec queryable_undeclare(entity_id()) :: :ok | {:error, reason :: term()}
   def queryable_undeclare(_queryable_id), do: err()
 
+  # Receiver
+
+  @spec receiver_recv(entity_id() | receiver(), non_neg_integer()) ::
+          {:ok, Zenohex.Receiver.item()} | {:error, :timeout} | {:error, reason :: term()}
+  def receiver_recv(_receiver, _timeout), do: err()
+
+  @spec receiver_try_recv(entity_id() | receiver()) ::
+          {:ok, Zenohex.Receiver.item()} | {:error, :empty} | {:error, reason :: term()}
+  def receiver_try_recv(_receiver), do: err()
+
+  @spec receiver_recv_many(entity_id() | receiver(), non_neg_integer(), non_neg_integer()) ::
+          {:ok, [Zenohex.Receiver.item()]} | {:error, reason :: term()}
+  def receiver_recv_many(_receiver, _max, _timeout), do: err()
+
+  # CancellationToken
+
+  @spec cancellation_token_new() :: cancellation_token()
+  def cancellation_token_new(), do: err()
+
+  @spec cancellation_token_cancel(cancellation_token()) :: :ok | {:error, reason :: term()}
+  def cancellation_token_cancel(_cancellation_token), do: err()
+
+  @spec cancellation_token_cancelled?(cancellation_token()) :: boolean()
+  def cancellation_token_cancelled?(_cancellation_token), do: err()
+
+  # Shm
+
+  @spec shm_provider_new(non_neg_integer(), non_neg_integer()) ::
+          {:ok, shm_provider()} | {:error, reason :: term()}
+  def shm_provider_new(_size, _alignment), do: err()
+
+  @spec shm_provider_alloc(shm_provider(), non_neg_integer()) ::
+          {:ok, shm_buffer()} | {:error, reason :: term()}
+  def shm_provider_alloc(_shm_provider, _size), do: err()
+
+  @spec shm_buffer_write(shm_buffer(), non_neg_integer(), binary()) ::
+          :ok | {:error, reason :: term()}
+  def shm_buffer_write(_shm_buffer, _offset, _binary), do: err()
+
+  @spec shm_buffer_len(shm_buffer()) :: non_neg_integer()
+  def shm_buffer_len(_shm_buffer), do: err()
+
+  # Serialization
+
+  @spec serialization_serialize(term(), Zenohex.Serialization.type()) ::
+          {:ok, binary()} | {:error, reason :: term()}
+  def serialization_serialize(_term, _type), do: err()
+
+  @spec serialization_deserialize(binary(), Zenohex.Serialization.type()) ::
+          {:ok, term()} | {:error, reason :: term()}
+  def serialization_deserialize(_binary, _type), do: err()
+
+  # EntityGlobalId
+
+  @spec entity_global_id_from_entity(entity_id()) :: Zenohex.EntityGlobalId.t()
+  def entity_global_id_from_entity(_entity_id), do: err()
+
+  # ZenohId
+
+  @spec zenoh_id_parse(String.t()) :: {:ok, Zenohex.ZenohId.t()} | {:error, reason :: term()}
+  def zenoh_id_parse(_string), do: err()
+
+  @spec zenoh_id_compare(Zenohex.ZenohId.t(), Zenohex.ZenohId.t()) ::
+          :lt | :eq | :gt | {:error, reason :: term()}
+  def zenoh_id_compare(_zid1, _zid2), do: err()
+
+  # Timestamp
+
+  @spec timestamp_parse(String.t()) :: {:ok, Zenohex.Timestamp.t()} | {:error, reason :: term()}
+  def timestamp_parse(_string), do: err()
+
+  @spec timestamp_compare(Zenohex.Timestamp.t(), Zenohex.Timestamp.t()) :: :lt | :eq | :gt
+  def timestamp_compare(_timestamp1, _timestamp2), do: err()
+
+  @spec timestamp_to_string(Zenohex.Timestamp.t()) :: String.t()
+  def timestamp_to_string(_timestamp), do: err()
+
+  @spec timestamp_time_to_unix_nanos(non_neg_integer()) :: non_neg_integer()
+  def timestamp_time_to_unix_nanos(_time), do: err()
+
+  @spec timestamp_time_from_unix_nanos(integer()) ::
+          {:ok, non_neg_integer()} | {:error, reason :: term()}
+  def timestamp_time_from_unix_nanos(_nanos), do: err()
+
   # Query
 
-  @spec query_reply(query(), String.t(), binary(), keyword()) ::
+  @spec query_reply(query(), Zenohex.KeyExpr.t(), binary(), keyword()) ::
           :ok | {:error, reason :: term()}
   def query_reply(_zenoh_query, _key_expr, _payload, _opts), do: err()
 
@@ -150,16 +390,14 @@ defmodule Zenohex.Nif do
           :ok | {:error, reason :: term()}
   def query_reply_error(_zenoh_query, _payload, _opts), do: err()
 
-  @spec query_reply_delete(query(), String.t(), keyword()) ::
+  @spec query_reply_delete(query(), Zenohex.KeyExpr.t(), keyword()) ::
           :ok | {:error, reason :: term()}
   def query_reply_delete(_zenoh_query, _key_expr, _opts), do: err()
 
   # KeyExpr
 
-  # NOTE: Not supported in Zenohex.
-  #       Use publisher instead, which is sufficient for all use cases.
-  # @spec keyexpr_undeclare(id(), key_expr :: reference()) :: :ok | {:error, term()}
-  # def keyexpr_undeclare(_session_id, _key_expr), do: err()
+  @spec keyexpr_undeclare(Zenohex.KeyExpr.declared()) :: :ok | {:error, reason :: term()}
+  def keyexpr_undeclare(_key_expr), do: err()
 
   @spec keyexpr_autocanonize(String.t()) :: {:ok, String.t()} | {:error, String.t()}
   def keyexpr_autocanonize(_key_expr), do: err()
@@ -176,26 +414,104 @@ defmodule Zenohex.Nif do
   @spec keyexpr_join(String.t(), String.t()) :: {:ok, String.t()} | {:error, String.t()}
   def keyexpr_join(_key_expr1, _key_expr2), do: err()
 
+  @spec keyexpr_canonize(String.t()) ::
+          {:ok, String.t()} | {:error, {:not_canon, String.t()}} | {:error, String.t()}
+  def keyexpr_canonize(_key_expr), do: err()
+
+  @spec keyexpr_validate(String.t()) :: :ok | {:error, Zenohex.KeyExpr.ValidationError.t()}
+  def keyexpr_validate(_key_expr), do: err()
+
+  @spec keyexpr_relation_to(String.t(), String.t()) :: Zenohex.KeyExpr.relation()
+  def keyexpr_relation_to(_key_expr1, _key_expr2), do: err()
+
+  @spec keyexpr_concat(String.t(), String.t()) :: {:ok, String.t()} | {:error, String.t()}
+  def keyexpr_concat(_key_expr, _suffix), do: err()
+
+  @spec keyexpr_chunks(String.t()) :: [String.t()]
+  def keyexpr_chunks(_key_expr), do: err()
+
+  @spec keyexpr_wild?(String.t()) :: boolean()
+  def keyexpr_wild?(_key_expr), do: err()
+
+  @spec keyexpr_strip_prefix(String.t(), String.t()) :: [String.t()]
+  def keyexpr_strip_prefix(_key_expr, _prefix), do: err()
+
+  @spec keyexpr_strip_nonwild_prefix(String.t(), String.t()) :: String.t() | nil
+  def keyexpr_strip_nonwild_prefix(_key_expr, _prefix), do: err()
+
+  # KeyExpr.Format
+
+  @spec keyexpr_format_new(String.t()) :: {:ok, keyexpr_format()} | {:error, reason :: term()}
+  def keyexpr_format_new(_format), do: err()
+
+  @spec keyexpr_format_build(keyexpr_format(), %{String.t() => String.t()}) ::
+          {:ok, String.t()} | {:error, reason :: term()}
+  def keyexpr_format_build(_format, _values), do: err()
+
+  @spec keyexpr_format_parse(keyexpr_format(), String.t()) ::
+          {:ok, %{String.t() => String.t()}} | {:error, reason :: term()}
+  def keyexpr_format_parse(_format, _key_expr), do: err()
+
+  # KeyExpr.Tree
+
+  @spec keyexpr_tree_new() :: keyexpr_tree()
+  def keyexpr_tree_new(), do: err()
+
+  @spec keyexpr_tree_insert(keyexpr_tree(), String.t(), term()) :: :ok
+  def keyexpr_tree_insert(_tree, _key_expr, _term), do: err()
+
+  @spec keyexpr_tree_remove(keyexpr_tree(), String.t()) :: :ok | {:error, :not_found}
+  def keyexpr_tree_remove(_tree, _key_expr), do: err()
+
+  @spec keyexpr_tree_intersecting(keyexpr_tree(), String.t()) :: [term()]
+  def keyexpr_tree_intersecting(_tree, _key_expr), do: err()
+
+  @spec keyexpr_tree_including(keyexpr_tree(), String.t()) :: [term()]
+  def keyexpr_tree_including(_tree, _key_expr), do: err()
+
+  @spec keyexpr_tree_included(keyexpr_tree(), String.t()) :: [term()]
+  def keyexpr_tree_included(_tree, _key_expr), do: err()
+
+  # Selector
+
+  @spec selector_split(String.t()) :: {:ok, {String.t(), String.t()}} | {:error, reason :: term()}
+  def selector_split(_selector), do: err()
+
+  @spec selector_parameters_parse(String.t()) :: %{String.t() => String.t()}
+  def selector_parameters_parse(_parameters), do: err()
+
+  @spec selector_parameters_values(String.t(), String.t()) :: [String.t()]
+  def selector_parameters_values(_parameters, _key), do: err()
+
+  @spec selector_parameters_build([{String.t(), [String.t()]}]) ::
+          {:ok, String.t()} | {:error, reason :: term()}
+  def selector_parameters_build(_entries), do: err()
+
+  @spec selector_parameters_time_range(String.t()) ::
+          {:ok, Zenohex.Selector.TimeRange.t() | nil} | {:error, reason :: term()}
+  def selector_parameters_time_range(_parameters), do: err()
+
+  @spec selector_time_range_parse(String.t()) ::
+          {:ok, Zenohex.Selector.TimeRange.t()} | {:error, reason :: term()}
+  def selector_time_range_parse(_time_range), do: err()
+
   # Liveliness
 
-  @spec liveliness_get(session_id(), String.t(), non_neg_integer(), keyword()) ::
+  @spec liveliness_get(session_id(), Zenohex.KeyExpr.t(), non_neg_integer(), keyword()) ::
           {:ok, [Zenohex.Sample.t() | Zenohex.Query.ReplyError.t()]}
           | {:error, :timeout}
           | {:error, reason :: term()}
   def liveliness_get(_session_id, _key_expr, _timeout, _opts \\ []), do: err()
 
-  # NOTE: Not supported in Zenohex.
-  #       Use `liveliness_get/4` for one-shot queries or
-  #       `liveliness_declare_subscriber/4` for liveliness monitoring.
-  # @spec liveliness_get_async(session_id(), String.t(), pid(), keyword()) ::
-  #         :ok | {:error, reason :: term()}
-  # def liveliness_get_async(_session_id, _key_expr, _pid, _opts \\ []), do: err()
+  @spec liveliness_get_async(session_id(), Zenohex.KeyExpr.t(), pid(), term(), keyword()) ::
+          :ok | {:error, reason :: term()}
+  def liveliness_get_async(_session_id, _key_expr, _pid, _ref, _opts), do: err()
 
-  @spec liveliness_declare_subscriber(session_id(), String.t(), pid(), keyword()) ::
+  @spec liveliness_declare_subscriber(session_id(), Zenohex.KeyExpr.t(), pid(), keyword()) ::
           {:ok, subscriber_id :: entity_id()} | {:error, reason :: term()}
   def liveliness_declare_subscriber(_session_id, _key_expr, _pid, _opts \\ []), do: err()
 
-  @spec liveliness_declare_token(session_id(), String.t()) ::
+  @spec liveliness_declare_token(session_id(), Zenohex.KeyExpr.t()) ::
           {:ok, liveliness_token()} | {:error, reason :: term()}
   def liveliness_declare_token(_session_id, _key_expr), do: err()
 
@@ -0,0 +1,165 @@
+defmodule Zenohex.Plugins do
+  @moduledoc """
+  Interface for managing the zenoh plugins of an open session.
+
+  Plugins listed in the `plugins` section of the config are loaded and started
+  when the session is opened. The functions of this module inspect them,
+  and start, stop or reload plugins afterwards, without reopening the session.
+
+  A plugin which failed to load or start, e.g. because of a bad `__path__`,
+  is still listed, with its errors in its `report`.
+
+  Plugins are usually run by routers, see `Zenohex.Examples.Plugins.StorageBackendFs`.
+  Loading dynamic plugins needs `plugins_loading: {enabled: true}` in the config.
+
+  ## Statically linked plugins
+
+  The following plugins can be linked into the NIF, by building it with cargo features.
+  They need neither `plugins_loading` nor shared libraries matching the zenoh version.
+
+  - `"plugin_rest"` for [zenoh-plugin-rest](https://zenoh.io/docs/apis/rest/), named `"rest"`
+  - `"plugin_storage_manager"` for
+    [zenoh-plugin-storage-manager](https://zenoh.io/docs/manual/plugin-storage-manager/),
+    named `"storage_manager"`, with its `"memory"` volume
+
+  ```elixir
+  # config/config.exs
+  config :rustler_precompiled, :force_build, zenohex: true
+  config :zenohex, nif_features: ["plugin_rest", "plugin_storage_manager"]
+  ```
+
+  They are then started from the config like dynamic plugins, unless a `__path__` is given.
+
+  ```json5
+  {
+    mode: "router",
+    plugins: {
+      rest: {http_port: 8000},
+      storage_manager: {storages: {demo: {key_expr: "demo/**", volume: "memory"}}},
+    },
+  }
+  ```
+
+  see. https://zenoh.io/docs/manual/plugins/
+
+  ## Examples
+
+      iex> {:ok, plugins} = Zenohex.Plugins.list(session_id)
+      iex> for %Zenohex.Plugins.Plugin{id: id, state: state} <- plugins, do: {id, state}
+      [{"storage_manager", :started}]
+  """
+
+  @type state :: :declared | :loaded | :started
+
+  @type start_opts :: [
+          name: String.t(),
+          paths: [String.t()] | nil,
+          required: boolean(),
+          config: Zenohex.Config.t()
+        ]
+
+  @type reload_opts :: [config: Zenohex.Config.t()]
+
+  defmodule Report do
+    @moduledoc """
+    The report of a plugin, e.g. the errors of its last load or start.
+    """
+
+    @type t :: %__MODULE__{level: :info | :warning | :error, messages: [String.t()]}
+    defstruct [:level, messages: []]
+  end
+
+  defmodule Plugin do
+    @moduledoc """
+    A plugin declared in the runtime of a session.
+
+    `path` is the path of the loaded library, `"__static_lib__"` for a statically linked plugin,
+    or `"__not_loaded__"` if it is not loaded.
+    """
+
+    @type t :: %__MODULE__{
+            id: String.t(),
+            name: String.t(),
+            version: String.t() | nil,
+            long_version: String.t() | nil,
+            path: String.t(),
+            state: Zenohex.Plugins.state(),
+            report: Zenohex.Plugins.Report.t()
+          }
+    defstruct [:id, :name, :version, :long_version, :path, :state, :report]
+  end
+
+  @doc """
+  Returns the plugins declared in the runtime of the session, whatever their state.
+  """
+  @spec list(Zenohex.Session.id()) :: {:ok, [Plugin.t()]} | {:error, reason :: term()}
+  defdelegate list(session_id), to: Zenohex.Nif, as: :plugins_list
+
+  @doc """
+  Loads and starts the plugin `id`, if it is not already started.
+
+  ## Options
+
+  - `:name` - the name of the plugin, or of its library `zenoh_plugin_<name>`.
+    Defaults to `id`.
+  - `:paths` - the paths of the plugin library, tried in order, instead of searching
+    for it by name in `plugins_loading/search_dirs`.
+  - `:required` - whether the runtime may panic on errors of the plugin. Defaults to `false`.
+  - `:config` - the JSON5 config of the plugin, set as `plugins/<id>` before it starts.
+
+  ## Examples
+
+      iex> Zenohex.Plugins.start(session_id, "storage_manager",
+      ...>   config: ~s({storages: {demo: {key_expr: "demo/**", volume: "memory"}}})
+      ...> )
+      :ok
+  """
+  @spec start(Zenohex.Session.id(), String.t(), start_opts()) ::
+          :ok | {:error, reason :: term()}
+  defdelegate start(session_id, id, opts \\ []), to: Zenohex.Nif, as: :plugins_start
+
+  @doc """
+  Stops the plugin `id`. It stays loaded, and can be started again.
+  """
+  @spec stop(Zenohex.Session.id(), String.t()) :: :ok | {:error, reason :: term()}
+  defdelegate stop(session_id, id), to: Zenohex.Nif, as: :plugins_stop
+
+  @doc """
+  Stops the plugin `id` if it is started, and starts it again.
+
+  The library of the plugin is not reloaded, only its config,
+  which is first replaced by `:config` if given.
+  """
+  @spec reload(Zenohex.Session.id(), String.t(), reload_opts()) ::
+          :ok | {:error, reason :: term()}
+  defdelegate reload(session_id, id, opts \\ []), to: Zenohex.Nif, as: :plugins_reload
+
+  @doc """
+  Returns the status of the started plugin `id`, or an empty map if it is not started.
+
+  This is what the plugin exposes in the admin space under
+  `@/<zid>/<whatami>/status/plugins/<id>/**`, but it is read from the plugin directly,
+  so it does not need `adminspace/enabled` in the config.
+
+  Keys are relative to this prefix. JSON values are decoded, others are kept as binaries.
+
+  ## Examples
+
+      iex> Zenohex.Plugins.status(session_id, "storage_manager")
+      {:ok, %{"__path__" => "/path/to/libzenoh_plugin_storage_manager.so", ...}}
+  """
+  @spec status(Zenohex.Session.id(), String.t()) ::
+          {:ok, %{String.t() => term()}} | {:error, reason :: term()}
+  def status(session_id, id) do
+    with {:ok, entries} <- Zenohex.Nif.plugins_status(session_id, id) do
+      {:ok, Map.new(entries, fn {key, value} -> {key, decode_status(value)} end)}
+    end
+  end
+
+  defp decode_status(value) do
+    case JSON.decode(value) do
+      {:ok, decoded} -> decoded
+      {:error, _reason} -> value
+    end
+  end
+end
@@ -12,17 +12,21 @@ defmodule Zenohex.Publisher do
   @type put_opts :: [
           attachment: binary() | nil,
           encoding: String.t(),
-          timestamp: Zenohex.Session.zenoh_timestamp_string()
+          source_info: Zenohex.SourceInfo.t() | nil,
+          timestamp: Zenohex.Timestamp.t()
         ]
   @type delete_opts :: [
           attachment: binary() | nil,
-          timestamp: Zenohex.Session.zenoh_timestamp_string()
+          source_info: Zenohex.SourceInfo.t() | nil,
+          timestamp: Zenohex.Timestamp.t()
         ]
 
   @doc """
   Sends a `kind: :put` sample with binary payload using the specified publisher.
+
+  The payload may also be a `t:Zenohex.Shm.buffer/0`, which is published without copying.
   """
-  @spec put(id(), binary(), put_opts()) :: :ok | {:error, reason :: term()}
+  @spec put(id(), binary() | Zenohex.Shm.buffer(), put_opts()) :: :ok | {:error, reason :: term()}
   defdelegate put(id, payload, opts \\ []), to: Zenohex.Nif, as: :publisher_put
 
   @doc """
@@ -11,9 +11,11 @@ defmodule Zenohex.Querier do
 
   @type get_opts :: [
           attachment: binary() | nil,
+          cancellation_token: Zenohex.CancellationToken.t(),
           encoding: String.t(),
           parameters: String.t(),
-          payload: binary() | nil
+          payload: binary() | nil,
+          source_info: Zenohex.SourceInfo.t() | nil
         ]
 
   @doc """
@@ -32,12 +34,31 @@ defmodule Zenohex.Querier do
   @doc """
   Executes a query asynchronously using the specified querier.
 
-  Each reply is sent as a message (`%Zenohex.Sample{}` or `%Zenohex.Query.ReplyError{}`)
-  to `pid`. Unlike `get/3`, there is no way to know when all replies have been received;
-  use an application-level protocol to determine when to stop waiting.
+  Each reply is sent to `pid` as `{:zenohex_get_reply, ref, reply}`, where `reply` is
+  `%Zenohex.Sample{}` or `%Zenohex.Query.ReplyError{}`. Once all replies have been received,
+  `{:zenohex_get_done, ref}` is sent. See `t:Zenohex.Session.get_async_message/0`.
+
+  The `:ref` option sets the reference used to tag replies. Defaults to `make_ref/0`.
+  """
+  @spec get_async(id(), pid(), get_opts() | Zenohex.Session.get_async_opts()) ::
+          {:ok, ref :: term()} | {:error, reason :: term()}
+  def get_async(id, pid \\ self(), opts \\ []) do
+    {ref, opts} = Keyword.pop_lazy(opts, :ref, &make_ref/0)
+
+    with :ok <- Zenohex.Nif.querier_get_async(id, pid, ref, opts) do
+      {:ok, ref}
+    end
+  end
+
+  @doc """
+  Executes a query using the specified querier and collects replies in a pull-mode receiver.
+
+  Replies are stored in the native `handler` until they are pulled with `Zenohex.Receiver`.
+  Once all replies have been received, the receiver returns an error.
   """
-  @spec get_async(id(), pid(), get_opts()) :: :ok | {:error, reason :: term()}
-  defdelegate get_async(id, pid \\ self(), opts \\ []), to: Zenohex.Nif, as: :querier_get_async
+  @spec get_pull(id(), Zenohex.Receiver.handler(), get_opts()) ::
+          {:ok, Zenohex.Receiver.t()} | {:error, reason :: term()}
+  defdelegate get_pull(id, handler, opts \\ []), to: Zenohex.Nif, as: :querier_get_pull
 
   @doc """
   Undeclares the querier identified by the given ID.
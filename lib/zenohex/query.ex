@@ -17,6 +17,7 @@ defmodule Zenohex.Query do
           parameters: String.t(),
           payload: binary() | nil,
           selector: String.t(),
+          source_info: Zenohex.SourceInfo.t() | nil,
           zenoh_query: zenoh_query()
         }
 
@@ -25,7 +26,8 @@ defmodule Zenohex.Query do
           attachment: binary() | nil,
           encoding: String.t(),
           express: boolean(),
-          timestamp: Zenohex.Session.zenoh_timestamp_string() | nil
+          source_info: Zenohex.SourceInfo.t() | nil,
+          timestamp: Zenohex.Timestamp.t() | nil
         ]
 
   @type reply_error_opts :: [
@@ -37,7 +39,8 @@ defmodule Zenohex.Query do
           final?: boolean(),
           attachment: binary() | nil,
           express: boolean(),
-          timestamp: Zenohex.Session.zenoh_timestamp_string() | nil
+          source_info: Zenohex.SourceInfo.t() | nil,
+          timestamp: Zenohex.Timestamp.t() | nil
         ]
 
   defstruct [
@@ -47,6 +50,7 @@ defmodule Zenohex.Query do
     :parameters,
     :payload,
     :selector,
+    :source_info,
     :zenoh_query
   ]
 
@@ -81,7 +85,7 @@ defmodule Zenohex.Query do
 
       iex> Zenohex.Query.reply(query.zenoh_query, "key/expr", "payload")
   """
-  @spec reply(zenoh_query(), String.t(), binary(), reply_opts()) ::
+  @spec reply(zenoh_query(), Zenohex.KeyExpr.t(), binary(), reply_opts()) ::
           :ok | {:error, reason :: term()}
   defdelegate reply(zenoh_query, key_expr, payload, opts \\ [final?: true]),
     to: Zenohex.Nif,
@@ -117,7 +121,7 @@ defmodule Zenohex.Query do
 
       iex> Zenohex.Query.reply_delete(query.zenoh_query, "key/expr")
   """
-  @spec reply_delete(zenoh_query(), String.t(), reply_delete_opts()) ::
+  @spec reply_delete(zenoh_query(), Zenohex.KeyExpr.t(), reply_delete_opts()) ::
           :ok | {:error, reason :: term()}
   defdelegate reply_delete(zenoh_query, key_expr, opts \\ [final?: true]),
     to: Zenohex.Nif,
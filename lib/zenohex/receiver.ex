@@ -0,0 +1,51 @@
+defmodule Zenohex.Receiver do
+  @moduledoc """
+  Interface for pulling samples, queries and replies from pull-mode entities.
+
+  Pull-mode entities store incoming data in a bounded handler inside the native layer
+  instead of sending it to a process. They are created with
+  `Zenohex.Session.declare_pull_subscriber/4`, `Zenohex.Session.declare_pull_queryable/4`,
+  `Zenohex.Session.get_pull/4` and `Zenohex.Querier.get_pull/3`.
+
+  Two handlers are supported,
+
+  - `{:fifo, capacity}` : Keeps the items in order. When full, new items are dropped
+    until some are pulled, so that a put or get of the same session never blocks.
+  - `{:ring, capacity}` : Keeps only the latest `capacity` items, dropping the oldest ones.
+
+  see. https://docs.rs/zenoh/latest/zenoh/handlers/index.html
+  """
+
+  @type t :: Zenohex.Subscriber.id() | Zenohex.Queryable.id() | reference()
+
+  @type handler :: {:fifo, pos_integer()} | {:ring, pos_integer()}
+
+  @type item :: Zenohex.Sample.t() | Zenohex.Query.t() | Zenohex.Query.ReplyError.t()
+
+  @doc """
+  Waits up to `timeout` milliseconds for the next item.
+
+  Returns `{:error, :timeout}` if nothing was received in time.
+  """
+  @spec recv(t(), non_neg_integer()) ::
+          {:ok, item()} | {:error, :timeout} | {:error, reason :: term()}
+  defdelegate recv(receiver, timeout), to: Zenohex.Nif, as: :receiver_recv
+
+  @doc """
+  Returns the next item without waiting.
+
+  Returns `{:error, :empty}` if nothing has been received yet.
+  """
+  @spec try_recv(t()) :: {:ok, item()} | {:error, :empty} | {:error, reason :: term()}
+  defdelegate try_recv(receiver), to: Zenohex.Nif, as: :receiver_try_recv
+
+  @doc """
+  Receives up to `max` items, waiting at most `timeout` milliseconds in total.
+
+  Returns the items received so far when the timeout expires, which may be an empty list.
+  This suits demand-driven consumers such as `GenStage` producers.
+  """
+  @spec recv_many(t(), non_neg_integer(), non_neg_integer()) ::
+          {:ok, [item()]} | {:error, reason :: term()}
+  defdelegate recv_many(receiver, max, timeout), to: Zenohex.Nif, as: :receiver_recv_many
+end
@@ -2,6 +2,8 @@ defmodule Zenohex.Sample do
   @moduledoc """
   A struct that corresponds one-to-one to `zenoh::sample::Sample`.
 
+  `shm` is `true` when the payload was received through shared memory.
+
   see. https://docs.rs/zenoh/latest/zenoh/sample/struct.Sample.html
   """
 
@@ -16,7 +18,10 @@ defmodule Zenohex.Sample do
           kind: :put | :delete,
           payload: binary(),
           priority: Zenohex.Session.priority(),
-          timestamp: Zenohex.Session.zenoh_timestamp_string() | nil
+          reliability: Zenohex.Session.reliability(),
+          shm: boolean(),
+          source_info: Zenohex.SourceInfo.t() | nil,
+          timestamp: Zenohex.Timestamp.t() | nil
         }
   defstruct attachment: nil,
             congestion_control: :block,
@@ -26,5 +31,8 @@ defmodule Zenohex.Sample do
             kind: :put,
             payload: "payload",
             priority: :data,
+            reliability: :reliable,
+            shm: false,
+            source_info: nil,
             timestamp: nil
 end
@@ -0,0 +1,19 @@
+defmodule Zenohex.SampleMiss do
+  @moduledoc """
+  A struct that corresponds one-to-one to `zenoh_ext::Miss`.
+
+  Sent to the process of an advanced subscriber when samples of a publisher
+  were missed and could not be recovered.
+
+  - `source` : Global ID of the publisher.
+  - `nb` : Number of missed samples.
+
+  see. https://docs.rs/zenoh-ext/latest/zenoh_ext/struct.Miss.html
+  """
+
+  @type t :: %__MODULE__{
+          source: Zenohex.EntityGlobalId.t(),
+          nb: pos_integer()
+        }
+  defstruct [:source, :nb]
+end
@@ -0,0 +1,164 @@
+defmodule Zenohex.Selector do
+  @moduledoc """
+  Parsing and building of selectors and their parameters, backed by zenoh's `Selector` and `Parameters`.
+
+  A selector is a key expression optionally followed by `?` and parameters,
+  e.g. `"demo/**?_time=[now(-1h)..];limit=10"`.
+  Parameters are `;`-separated `key=value` entries, and a value may hold several values
+  separated by `|`. The syntax has no escape sequence.
+
+  `Zenohex.Query` gives the `selector` and `parameters` of a query as strings.
+  `Zenohex.Session.get/4` takes a selector string, or a key expression with parameters
+  as its `:parameters` option, like `Zenohex.Querier.get/3`.
+
+  see. https://docs.rs/zenoh/latest/zenoh/query/struct.Selector.html
+
+  ## Examples
+
+      iex> {:ok, {key_expr, parameters}} = Zenohex.Selector.split("demo/**?_time=[..];limit=10")
+      {:ok, {"demo/**", "_time=[..];limit=10"}}
+      iex> Zenohex.Selector.parse_parameters(parameters)
+      %{"_time" => "[..]", "limit" => "10"}
+      iex> Zenohex.Selector.build_parameters(%{"limit" => 10, "fields" => ["a", "b"]})
+      {:ok, "fields=a|b;limit=10"}
+  """
+
+  defmodule TimeRange do
+    @moduledoc """
+    The time range of the standardized `_time` parameter, e.g. `"[now(-1h)..now()]"`.
+
+    A bound is `:unbounded`, or `{:inclusive, time}` or `{:exclusive, time}`.
+    A time is either a `DateTime`, or `{:now, offset_secs}` which is relative
+    to the instant the range is evaluated at, see `resolve/2`.
+
+    `DateTime` has microsecond precision, so the sub-microsecond part of an instant is truncated.
+
+    see. https://docs.rs/zenoh/latest/zenoh/query/struct.TimeRange.html
+    """
+
+    @type time :: DateTime.t() | {:now, offset_secs :: float()}
+    @type bound :: :unbounded | {:inclusive, time()} | {:exclusive, time()}
+
+    @type t :: %__MODULE__{start: bound(), end: bound()}
+    @enforce_keys [:start, :end]
+    defstruct [:start, :end]
+
+    @doc """
+    Parses a time range, e.g. `"[2025-07-16T00:00:00Z..now()["` or `"[now(-1h);30m]"`.
+    """
+    @spec parse(String.t()) :: {:ok, t()} | {:error, reason :: term()}
+    def parse(string) do
+      with {:ok, time_range} <- Zenohex.Nif.selector_time_range_parse(string) do
+        {:ok, from_nif(time_range)}
+      end
+    end
+
+    @doc """
+    Replaces the `{:now, offset_secs}` times of the range by `now` plus their offset.
+    """
+    @spec resolve(t(), DateTime.t()) :: t()
+    def resolve(%__MODULE__{} = time_range, now \\ DateTime.utc_now()) do
+      %{
+        time_range
+        | start: resolve_bound(time_range.start, now),
+          end: resolve_bound(time_range.end, now)
+      }
+    end
+
+    @doc """
+    Returns whether `datetime` is within the range, resolved at `now`.
+    """
+    @spec contains?(t(), DateTime.t(), DateTime.t()) :: boolean()
+    def contains?(%__MODULE__{} = time_range, datetime, now \\ DateTime.utc_now()) do
+      %__MODULE__{start: start, end: end_} = resolve(time_range, now)
+
+      after_start?(start, datetime) and before_end?(end_, datetime)
+    end
+
+    defp after_start?(:unbounded, _datetime), do: true
+    defp after_start?({:inclusive, time}, datetime), do: DateTime.compare(time, datetime) != :gt
+    defp after_start?({:exclusive, time}, datetime), do: DateTime.compare(time, datetime) == :lt
+
+    defp before_end?(:unbounded, _datetime), do: true
+    defp before_end?({:inclusive, time}, datetime), do: DateTime.compare(time, datetime) != :lt
+    defp before_end?({:exclusive, time}, datetime), do: DateTime.compare(time, datetime) == :gt
+
+    defp resolve_bound(:unbounded, _now), do: :unbounded
+    defp resolve_bound({inclusivity, time}, now), do: {inclusivity, resolve_time(time, now)}
+
+    defp resolve_time({:now, offset_secs}, now),
+      do: DateTime.add(now, round(offset_secs * 1_000_000), :microsecond)
+
+    defp resolve_time(%DateTime{} = datetime, _now), do: datetime
+
+    @doc false
+    def from_nif(%__MODULE__{start: start, end: end_}) do
+      %__MODULE__{start: bound_from_nif(start), end: bound_from_nif(end_)}
+    end
+
+    defp bound_from_nif(:unbounded), do: :unbounded
+    defp bound_from_nif({inclusivity, time}), do: {inclusivity, time_from_nif(time)}
+
+    defp time_from_nif({:fixed, nanos}), do: DateTime.from_unix!(nanos, :nanosecond)
+    defp time_from_nif({:now, offset_secs}), do: {:now, offset_secs}
+  end
+
+  @doc """
+  Splits `selector` into its key expression and its parameters.
+
+  An error is returned if the key expression is invalid.
+  """
+  @spec split(String.t()) :: {:ok, {String.t(), String.t()}} | {:error, reason :: term()}
+  defdelegate split(selector), to: Zenohex.Nif, as: :selector_split
+
+  @doc """
+  Parses `parameters` into a map.
+
+  Multiple values are kept joined by `|`, see `parameter_values/2`.
+  If a key is repeated, its first entry wins.
+  """
+  @spec parse_parameters(String.t()) :: %{String.t() => String.t()}
+  defdelegate parse_parameters(parameters), to: Zenohex.Nif, as: :selector_parameters_parse
+
+  @doc """
+  Returns the `|`-separated values of `key` in `parameters`, or `[]` if `key` is absent.
+  """
+  @spec parameter_values(String.t(), String.t()) :: [String.t()]
+  defdelegate parameter_values(parameters, key), to: Zenohex.Nif, as: :selector_parameters_values
+
+  @doc """
+  Builds a parameters string from `parameters`.
+
+  Keys and values are converted with `to_string/1`, and a list value gives multiple values.
+  Since the syntax has no escape sequence, an error is returned if a key is empty
+  or contains `;` or `=`, or if a value contains `;` or `|`.
+  """
+  @spec build_parameters(
+          Enumerable.t({atom() | String.t(), String.Chars.t() | [String.Chars.t()]})
+        ) :: {:ok, String.t()} | {:error, reason :: term()}
+  def build_parameters(parameters) do
+    parameters
+    |> Enum.map(fn {key, value} ->
+      {to_string(key), value |> List.wrap() |> Enum.map(&to_string/1)}
+    end)
+    |> Zenohex.Nif.selector_parameters_build()
+  end
+
+  @doc """
+  Returns the time range of the `_time` parameter of `parameters`,
+  or `nil` if there is no `_time` parameter.
+
+  ## Examples
+
+      iex> Zenohex.Selector.time_range("_time=[now(-1h)..]")
+      {:ok, %Zenohex.Selector.TimeRange{start: {:inclusive, {:now, -3600.0}}, end: :unbounded}}
+  """
+  @spec time_range(String.t()) :: {:ok, TimeRange.t() | nil} | {:error, reason :: term()}
+  def time_range(parameters) do
+    case Zenohex.Nif.selector_parameters_time_range(parameters) do
+      {:ok, nil} -> {:ok, nil}
+      {:ok, time_range} -> {:ok, TimeRange.from_nif(time_range)}
+      {:error, reason} -> {:error, reason}
+    end
+  end
+end
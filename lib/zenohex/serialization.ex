@@ -0,0 +1,67 @@
+defmodule Zenohex.Serialization do
+  @moduledoc """
+  Codec for the zenoh-ext serialization format.
+
+  Payloads and attachments serialized with `zenoh_ext::z_serialize` in Rust,
+  or with the equivalent functions of the other Zenoh bindings, can be decoded with
+  `deserialize/2`, and `serialize/2` produces the same bytes as zenoh-ext.
+
+  The wire format carries no type information, so both functions take a type spec.
+
+  - `:u8`, `:u16`, `:u32`, `:u64`, `:i8`, `:i16`, `:i32`, `:i64` : integers of the given width
+  - `:f32`, `:f64` : floats
+  - `:bool` : booleans
+  - `:string` : UTF-8 binaries, `String` in Rust
+  - `:bytes` : binaries, `Vec<u8>` in Rust
+  - `{:tuple, [type]}` : Elixir tuples, Rust tuples
+  - `{:list, type}` : Elixir lists, `Vec<T>` in Rust
+  - `{:map, key_type, value_type}` : Elixir maps, `HashMap<K, V>` in Rust
+
+  see. https://docs.rs/zenoh-ext/latest/zenoh_ext/fn.z_serialize.html
+
+  ## Examples
+
+      iex> type = {:tuple, [:i32, :string, {:list, :f64}]}
+      iex> {:ok, payload} = Zenohex.Serialization.serialize({1, "one", [1.0]}, type)
+      iex> :ok = Zenohex.Session.put(session_id, "key/expr", payload)
+      iex> Zenohex.Serialization.deserialize(payload, type)
+      {:ok, {1, "one", [1.0]}}
+  """
+
+  @type scalar_type ::
+          :u8
+          | :u16
+          | :u32
+          | :u64
+          | :i8
+          | :i16
+          | :i32
+          | :i64
+          | :f32
+          | :f64
+          | :bool
+          | :string
+          | :bytes
+
+  @type type ::
+          scalar_type()
+          | {:tuple, [type()]}
+          | {:list, type()}
+          | {:map, key_type :: type(), value_type :: type()}
+
+  @doc """
+  Serializes `term` as the given `type`.
+
+  The result can be used as a payload or an attachment.
+  """
+  @spec serialize(term(), type()) :: {:ok, binary()} | {:error, reason :: term()}
+  defdelegate serialize(term, type), to: Zenohex.Nif, as: :serialization_serialize
+
+  @doc """
+  Deserializes `binary` as the given `type`.
+
+  Returns an error if `binary` is too short or has bytes left after deserialization.
+  """
+  @spec deserialize(binary(), type()) :: {:ok, term()} | {:error, reason :: term()}
+  defdelegate deserialize(binary, type), to: Zenohex.Nif, as: :serialization_deserialize
+end
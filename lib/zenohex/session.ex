@@ -25,20 +25,9 @@ defmodule Zenohex.Session do
   @type id :: reference()
 
   @typedoc """
-  The global unique id of a Zenoh session.
-
-  see. https://docs.rs/zenoh/latest/zenoh/session/struct.ZenohId.html
-  """
-  @type zid :: String.t()
-
-  @typedoc """
-  A Timestamp is formatted to a String as such: "<RFC3339> /<hlc_id_hexadecimal>"
-
-  `2025-07-16T01:34:56.871273403Z/208a2ec783ec4527a39cc1d5559c70e9`
-
-  see. https://docs.rs/zenoh/latest/zenoh/time/struct.Timestamp.html
+  The global unique id of a Zenoh session, see `Zenohex.ZenohId`.
   """
-  @type zenoh_timestamp_string :: String.t()
+  @type zid :: Zenohex.ZenohId.t()
 
   @type congestion_control :: :drop | :block | :block_first
 
@@ -51,6 +40,7 @@ defmodule Zenohex.Session do
           | :data_low
           | :background
 
+  @type reliability :: :reliable | :best_effort
   @type query_target :: :best_matching | :all | :all_complete
   @type query_consolidation :: :auto | :none | :monotonic | :latest
   @type reply_key_expr :: :matching_query | :any
@@ -62,7 +52,9 @@ defmodule Zenohex.Session do
           encoding: String.t(),
           express: boolean(),
           priority: priority(),
-          timestamp: zenoh_timestamp_string()
+          reliability: reliability(),
+          source_info: Zenohex.SourceInfo.t() | nil,
+          timestamp: Zenohex.Timestamp.t()
         ]
 
   @type delete_opts :: [
@@ -70,23 +62,40 @@ defmodule Zenohex.Session do
           congestion_control: congestion_control(),
           express: boolean(),
           priority: priority(),
-          timestamp: zenoh_timestamp_string()
+          reliability: reliability(),
+          source_info: Zenohex.SourceInfo.t() | nil,
+          timestamp: Zenohex.Timestamp.t()
         ]
 
   @type get_opts :: [
           accept_replies: reply_key_expr(),
           attachment: binary() | nil,
           allowed_destination: locality(),
+          cancellation_token: Zenohex.CancellationToken.t(),
           congestion_control: congestion_control(),
           consolidation: query_consolidation(),
           encoding: String.t(),
           express: boolean(),
+          parameters: String.t(),
           payload: binary() | nil,
           priority: priority(),
+          source_info: Zenohex.SourceInfo.t() | nil,
           target: query_target(),
           query_timeout: non_neg_integer()
         ]
 
+  @type get_async_opts :: [{:ref, term()} | {atom(), term()}]
+
+  @typedoc """
+  Messages sent to `pid` by asynchronous gets.
+
+  Every reply is tagged with the reference of the request, and
+  `{:zenohex_get_done, ref}` is sent once no more replies will follow.
+  """
+  @type get_async_message ::
+          {:zenohex_get_reply, ref :: term(), Zenohex.Sample.t() | Zenohex.Query.ReplyError.t()}
+          | {:zenohex_get_done, ref :: term()}
+
   @type querier_opts :: [
           accept_replies: reply_key_expr(),
           allowed_destination: locality(),
@@ -103,7 +112,8 @@ defmodule Zenohex.Session do
           congestion_control: congestion_control(),
           encoding: String.t(),
           express: boolean(),
-          priority: priority()
+          priority: priority(),
+          reliability: reliability()
         ]
 
   @type subscriber_opts :: [
@@ -115,26 +125,197 @@ defmodule Zenohex.Session do
           complete: boolean()
         ]
 
+  @typedoc """
+  Options of `declare_advanced_publisher/3`, in addition to `t:publisher_opts/0`.
+
+  - `cache` : Keeps the last samples so late-joining or recovering subscribers can fetch them.
+    `max_samples` defaults to 1.
+  - `sample_miss_detection` : Adds sequence numbers so subscribers can detect missed samples.
+    With `{:heartbeat, ms}` or `{:sporadic_heartbeat, ms}`, the last sequence number
+    is also published periodically, or periodically only when it changed.
+  - `publisher_detection` : Declares a liveliness token so advanced subscribers can detect
+    this publisher.
+
+  `true` enables an option with its default settings.
+  """
+  @type advanced_publisher_opts :: [
+          {:cache, boolean() | [max_samples: pos_integer()]}
+          | {:sample_miss_detection,
+             boolean()
+             | {:heartbeat, non_neg_integer()}
+             | {:sporadic_heartbeat, non_neg_integer()}}
+          | {:publisher_detection, boolean()}
+          | {atom(), term()}
+        ]
+
+  @typedoc """
+  Options of `declare_advanced_subscriber/4`, in addition to `t:subscriber_opts/0`.
+
+  - `history` : Fetches the samples cached by advanced publishers when declared.
+    `detect_late_publishers: true` also fetches from publishers detected later,
+    which requires `publisher_detection` on the publisher side.
+    `max_age` is given in milliseconds.
+  - `recovery` : Retrieves missed samples from the publisher cache. `:heartbeat` relies on
+    publisher heartbeats, `{:periodic_queries, ms}` queries the publishers periodically.
+  - `query_timeout` : Timeout in milliseconds of the history and recovery queries.
+  - `subscriber_detection` : Declares a liveliness token so this subscriber can be detected.
+
+  `true` enables an option with its default settings.
+  """
+  @type advanced_subscriber_opts :: [
+          {:history,
+           boolean()
+           | [
+               detect_late_publishers: boolean(),
+               max_age: non_neg_integer(),
+               max_samples: pos_integer()
+             ]}
+          | {:recovery, boolean() | :heartbeat | {:periodic_queries, non_neg_integer()}}
+          | {:query_timeout, non_neg_integer()}
+          | {:subscriber_detection, boolean()}
+          | {atom(), term()}
+        ]
+
   defmodule Info do
     @moduledoc """
     A struct that corresponds one-to-one to `zenoh::session::SessionInfo`.
 
+    - `zid` : The Zenoh ID of the session.
+    - `whatami` : The mode of the session.
+    - `locators` : The locators the session is listening on, with the actual ports
+      when `0` was configured. Unspecified addresses such as `0.0.0.0` are expanded
+      to the addresses of the interfaces, except loopback ones.
+    - `routers_zid` : The Zenoh IDs of the routers the session is connected to.
+    - `peers_zid` : The Zenoh IDs of the peers the session is connected to.
+    - `transports` : The transports to the remote zenoh nodes.
+
     see. https://docs.rs/zenoh/latest/zenoh/session/struct.SessionInfo.html
     """
 
     @type t :: %__MODULE__{
             zid: Zenohex.Session.zid(),
+            whatami: :router | :peer | :client,
+            locators: [String.t()],
             routers_zid: [Zenohex.Session.zid()] | [],
-            peers_zid: [Zenohex.Session.zid()] | []
+            peers_zid: [Zenohex.Session.zid()] | [],
+            transports: [Zenohex.Session.Transport.t()]
           }
 
     defstruct [
       :zid,
+      :whatami,
+      :locators,
       :routers_zid,
-      :peers_zid
+      :peers_zid,
+      :transports
+    ]
+  end
+
+  defmodule Transport do
+    @moduledoc """
+    A struct that corresponds one-to-one to `zenoh::session::Transport`.
+
+    A transport is a connection to a remote zenoh node, made of one or more links.
+
+    - `zid` : The Zenoh ID of the remote zenoh node.
+    - `whatami` : The mode of the remote zenoh node.
+    - `qos` : Whether the transport supports QoS.
+    - `multicast` : Whether the transport is multicast.
+    - `shm` : Whether the transport supports shared memory.
+    - `links` : The links of the transport.
+
+    see. https://docs.rs/zenoh/latest/zenoh/session/struct.Transport.html
+    """
+
+    @type t :: %__MODULE__{
+            zid: Zenohex.Session.zid(),
+            whatami: :router | :peer | :client,
+            qos: boolean(),
+            multicast: boolean(),
+            shm: boolean(),
+            links: [Zenohex.Session.Link.t()]
+          }
+
+    defstruct [:zid, :whatami, :qos, :multicast, :shm, links: []]
+  end
+
+  defmodule Link do
+    @moduledoc """
+    A struct that corresponds one-to-one to `zenoh::session::Link`.
+
+    - `protocol` : The protocol of the link, e.g. `"tcp"`.
+    - `src` : The local locator of the link.
+    - `dst` : The remote locator of the link.
+    - `group` : The multicast group locator, `nil` for unicast links.
+    - `mtu` : The MTU of the link.
+    - `streamed` : Whether the link is streamed, e.g. TCP.
+    - `interfaces` : The network interfaces the link is bound to.
+
+    see. https://docs.rs/zenoh/latest/zenoh/session/struct.Link.html
+    """
+
+    @type t :: %__MODULE__{
+            protocol: String.t(),
+            src: String.t(),
+            dst: String.t(),
+            group: String.t() | nil,
+            mtu: non_neg_integer(),
+            streamed: boolean(),
+            interfaces: [String.t()]
+          }
+
+    defstruct [:protocol, :src, :dst, :group, :mtu, :streamed, interfaces: []]
+  end
+
+  defmodule Entity do
+    @moduledoc """
+    A struct describing an entity declared on a session, returned by `Zenohex.Session.entities/1`.
+
+    - `id` : The entity id, usable with the functions of the entity module,
+      e.g. `Zenohex.Publisher.undeclare/1`. Unlike the id returned at declaration,
+      it does not keep the entity alive, and cannot be used with `Zenohex.Receiver`.
+    - `kind` : The kind of the entity.
+    - `key_expr` : The key expression given at declaration.
+    - `pid` : The process receiving messages from the entity, `nil` for publishers,
+      queriers and pull-mode entities.
+    - `opts` : The effective options of the entity, that is the options given at declaration
+      and the zenoh defaults of the others, e.g. the `query_timeout` of the session config
+      for queriers. Options the entity does not support are left out. Advanced options
+      are `false` when not given, except the `query_timeout` of advanced subscribers,
+      which is only listed when given.
+    """
+
+    @type kind ::
+            :publisher
+            | :advanced_publisher
+            | :querier
+            | :subscriber
+            | :advanced_subscriber
+            | :liveliness_subscriber
+            | :queryable
+
+    @type t :: %__MODULE__{
+            id: reference(),
+            kind: kind(),
+            key_expr: String.t(),
+            pid: pid() | nil,
+            opts: keyword()
+          }
+
+    defstruct [
+      :id,
+      :kind,
+      :key_expr,
+      :pid,
+      :opts
     ]
   end
 
+  @type undeclare_all_opts :: [
+          kind: Entity.kind() | [Entity.kind()],
+          key_expr: Zenohex.KeyExpr.t()
+        ]
+
   @doc """
   Opens a session using Zenoh default config.
 
@@ -212,7 +393,7 @@ defmodule Zenohex.Session do
 
   - `session_id` : The session identifier returned by `open/0` or `open/1`.
   - `key_expr` : The key expression to publish to.
-  - `payload` : The value to publish, as a binary.
+  - `payload` : The value to publish, as a binary or a `t:Zenohex.Shm.buffer/0`.
   - `opts` : Options for the publish operation.
 
   ## Examples
@@ -221,7 +402,12 @@ defmodule Zenohex.Session do
       iex> Zenohex.Session.put(session_id, "key/expr", "payload")
       :ok
   """
-  @spec put(session_id :: id(), String.t(), binary(), put_opts()) ::
+  @spec put(
+          session_id :: id(),
+          Zenohex.KeyExpr.t(),
+          binary() | Zenohex.Shm.buffer(),
+          put_opts()
+        ) ::
           :ok | {:error, reason :: term()}
   defdelegate put(session_id, key_expr, payload, opts \\ []),
     to: Zenohex.Nif,
@@ -242,7 +428,7 @@ defmodule Zenohex.Session do
       iex> Zenohex.Session.delete(session_id, "key/expr")
       :ok
   """
-  @spec delete(session_id :: id(), String.t(), delete_opts()) ::
+  @spec delete(session_id :: id(), Zenohex.KeyExpr.t(), delete_opts()) ::
           :ok | {:error, reason :: term()}
   defdelegate delete(session_id, key_expr, opts \\ []),
     to: Zenohex.Nif,
@@ -254,7 +440,8 @@ defmodule Zenohex.Session do
   ## Parameters
 
   - `session_id` : The session identifier returned by `open/0` or `open/1`.
-  - `selector` : The selector to query.
+  - `selector` : The selector to query, or a key expression declared by `declare_keyexpr/2`.
+    The `:parameters` option is appended to the parameters of the selector, if any.
   - `timeout` : Timeout in milliseconds to wait for query replies.
   - `opts` : Options for the get operation.
 
@@ -264,7 +451,7 @@ defmodule Zenohex.Session do
       iex> Zenohex.Session.get(session_id, "key/expr")
       {:ok, [%Zenohex.Sample{}]}
   """
-  @spec get(session_id :: id(), String.t(), non_neg_integer(), get_opts()) ::
+  @spec get(session_id :: id(), Zenohex.KeyExpr.t(), non_neg_integer(), get_opts()) ::
           {:ok, [Zenohex.Sample.t() | Zenohex.Query.ReplyError.t()]}
           | {:error, :timeout}
           | {:error, reason :: term()}
@@ -273,7 +460,79 @@ defmodule Zenohex.Session do
     as: :session_get
 
   @doc """
-  New zenoh timestamp string associated with the given session.
+  Query data with the given `selector` asynchronously.
+
+  Unlike `get/4`, this function returns immediately without occupying a dirty scheduler.
+  Replies are delivered to `pid` as `t:get_async_message/0` messages, tagged with
+  the returned reference, followed by `{:zenohex_get_done, ref}`.
+
+  ## Parameters
+
+  - `session_id` : The session identifier returned by `open/0` or `open/1`.
+  - `selector` : The selector to query, or a key expression declared by `declare_keyexpr/2`.
+    The `:parameters` option is appended to the parameters of the selector, if any.
+  - `pid` : Process to receive replies. Defaults to the calling process.
+  - `opts` : Options for the get operation. In addition to `t:get_opts/0`,
+    `:ref` sets the reference used to tag replies. Defaults to `make_ref/0`.
+
+  ## Examples
+
+      iex> {:ok, session_id} = Zenohex.Session.open()
+      iex> {:ok, ref} = Zenohex.Session.get_async(session_id, "key/expr")
+      iex> receive do
+      ...>   {:zenohex_get_reply, ^ref, %Zenohex.Sample{} = sample} -> sample
+      ...> end
+  """
+  @spec get_async(
+          session_id :: id(),
+          Zenohex.KeyExpr.t(),
+          pid(),
+          get_opts() | get_async_opts()
+        ) ::
+          {:ok, ref :: term()} | {:error, reason :: term()}
+  def get_async(session_id, selector, pid \\ self(), opts \\ []) do
+    {ref, opts} = Keyword.pop_lazy(opts, :ref, &make_ref/0)
+
+    with :ok <- Zenohex.Nif.session_get_async(session_id, selector, pid, ref, opts) do
+      {:ok, ref}
+    end
+  end
+
+  @doc """
+  Query data with the given `selector` and collect replies in a pull-mode receiver.
+
+  Unlike `get/4`, this function returns immediately. Replies are stored in the
+  native `handler` until they are pulled with `Zenohex.Receiver`.
+  Once all replies have been received, the receiver returns an error.
+
+  ## Parameters
+
+  - `session_id` : The session identifier returned by `open/0` or `open/1`.
+  - `selector` : The selector to query, or a key expression declared by `declare_keyexpr/2`.
+    The `:parameters` option is appended to the parameters of the selector, if any.
+  - `handler` : `{:fifo, capacity}` or `{:ring, capacity}`. See `Zenohex.Receiver`.
+  - `opts` : Options for the get operation.
+
+  ## Examples
+
+      iex> {:ok, session_id} = Zenohex.Session.open()
+      iex> {:ok, receiver} = Zenohex.Session.get_pull(session_id, "key/expr", {:fifo, 16})
+      iex> Zenohex.Receiver.recv_many(receiver, 16, 100)
+      {:ok, [%Zenohex.Sample{}]}
+  """
+  @spec get_pull(
+          session_id :: id(),
+          Zenohex.KeyExpr.t(),
+          Zenohex.Receiver.handler(),
+          get_opts()
+        ) ::
+          {:ok, Zenohex.Receiver.t()} | {:error, reason :: term()}
+  defdelegate get_pull(session_id, selector, handler, opts \\ []),
+    to: Zenohex.Nif,
+    as: :session_get_pull
+
+  @doc """
+  New zenoh timestamp generated by the HLC of the given session.
 
   ## Parameters
 
@@ -282,16 +541,29 @@ defmodule Zenohex.Session do
   ## Examples
 
       iex> {:ok, session_id} = Zenohex.Session.open()
-      iex> {:ok, zenoh_timestamp} = Zenohex.Session.new_timestamp(session_id)
-      iex> [timestamp, zenoh_id_string] = String.split(zenoh_timestamp, "/")
-      iex> {:ok, %DateTime{}, 0} = DateTime.from_iso8601(timestamp)
+      iex> {:ok, timestamp} = Zenohex.Session.new_timestamp(session_id)
+      iex> %DateTime{} = Zenohex.Timestamp.to_datetime(timestamp)
   """
   @spec new_timestamp(session_id :: id()) ::
-          {:ok, zenoh_timestamp_string()} | {:error, reason :: term()}
+          {:ok, Zenohex.Timestamp.t()} | {:error, reason :: term()}
   defdelegate new_timestamp(session_id),
     to: Zenohex.Nif,
     as: :session_new_timestamp
 
+  @doc """
+  Updates the HLC of the given session with a timestamp received from a remote source.
+
+  Timestamps generated afterwards by the session are greater than `timestamp`.
+  Returns an error if `timestamp` is too far in the future compared to the local clock.
+
+  see. https://docs.rs/uhlc/latest/uhlc/struct.HLC.html#method.update_with_timestamp
+  """
+  @spec update_with_timestamp(session_id :: id(), Zenohex.Timestamp.t()) ::
+          :ok | {:error, reason :: term()}
+  defdelegate update_with_timestamp(session_id, timestamp),
+    to: Zenohex.Nif,
+    as: :session_update_with_timestamp
+
   @doc """
   Get information about the zenoh Session.
 
@@ -309,6 +581,156 @@ defmodule Zenohex.Session do
     to: Zenohex.Nif,
     as: :session_info
 
+  @doc """
+  Returns the effective configuration of the running session as a JSON string.
+
+  It can be read with `Zenohex.Config.get_json/2`, and includes the changes
+  made by `config_insert_json5/3` and `Zenohex.Plugins`.
+
+  ## Examples
+
+      iex> {:ok, session_id} = Zenohex.Session.open()
+      iex> {:ok, config} = Zenohex.Session.config(session_id)
+      iex> is_binary(config)
+      true
+  """
+  @spec config(session_id :: id()) :: {:ok, Zenohex.Config.t()} | {:error, reason :: term()}
+  defdelegate config(session_id),
+    to: Zenohex.Nif,
+    as: :session_config
+
+  @doc """
+  Returns the JSON string of the value at `key` in the configuration of the running session.
+
+  ## Examples
+
+      iex> Zenohex.Session.config_get_json(session_id, "connect/endpoints")
+      {:ok, "[\"tcp/localhost:7447\"]"}
+  """
+  @spec config_get_json(session_id :: id(), String.t()) ::
+          {:ok, String.t()} | {:error, reason :: term()}
+  defdelegate config_get_json(session_id, key),
+    to: Zenohex.Nif,
+    as: :session_config_get_json
+
+  @doc """
+  Inserts or updates a JSON5 value at `key` in the configuration of the running session.
+
+  `value` is handled as by `Zenohex.Config.insert_json5/3`,
+  a plain string is quoted and a list is encoded as a JSON array.
+
+  zenoh applies configuration changes live only for keys under `plugins/`,
+  e.g. `"plugins/storage_manager/storages/demo"`, and returns an error for any other key.
+  The other settings, such as `connect/endpoints` or `downsampling`,
+  are read once when the session opens and need a new session to change.
+  To connect a running session to a new endpoint, use `connect/2`.
+
+  ## Examples
+
+      iex> Zenohex.Session.config_insert_json5(session_id,
+      ...>   "plugins/storage_manager/storages/demo",
+      ...>   ~s({key_expr: "demo/**", volume: "memory"})
+      ...> )
+      :ok
+      iex> Zenohex.Session.config_insert_json5(session_id, "connect/endpoints", ["tcp/localhost:7447"])
+      {:error, "...updating config is only supported for keys starting with `plugins/`"}
+  """
+  @spec config_insert_json5(session_id :: id(), String.t(), String.t() | list()) ::
+          :ok | {:error, reason :: term()}
+  def config_insert_json5(session_id, key, value) do
+    Zenohex.Config.insert_json5_with(
+      &Zenohex.Nif.session_config_insert_json5(session_id, &1, &2),
+      key,
+      value
+    )
+  end
+
+  @doc """
+  Connects the running session to a new endpoint, e.g. to add a router to a running node.
+
+  The zenoh node listening on `endpoint` is identified first by a client session
+  which is opened to it and closed right away, so that node sees a short-lived client.
+
+  Unlike the endpoints of `connect/endpoints`, the endpoint is not added to the configuration,
+  and zenoh does not reconnect to it if the transport closes.
+
+  ## Examples
+
+      iex> Zenohex.Session.connect(session_id, "tcp/192.168.1.10:7447")
+      :ok
+  """
+  @spec connect(session_id :: id(), String.t()) :: :ok | {:error, reason :: term()}
+  defdelegate connect(session_id, endpoint), to: Zenohex.Nif, as: :session_connect
+
+  @doc """
+  Lists the entities declared on the session.
+
+  Liveliness tokens, matching listeners and scouts are not session entities and are not listed.
+
+  ## Examples
+
+      iex> {:ok, session_id} = Zenohex.Session.open()
+      iex> {:ok, _publisher_id} = Zenohex.Session.declare_publisher(session_id, "key/expr")
+      iex> {:ok, [%Zenohex.Session.Entity{kind: :publisher, key_expr: "key/expr"}]} =
+      ...>   Zenohex.Session.entities(session_id)
+  """
+  @spec entities(session_id :: id()) ::
+          {:ok, [Zenohex.Session.Entity.t()]} | {:error, reason :: term()}
+  defdelegate entities(session_id),
+    to: Zenohex.Nif,
+    as: :session_entities
+
+  @doc """
+  Undeclares the entities of the session at once, and returns how many were undeclared.
+
+  All matching entities are removed from the session before any is undeclared,
+  so other calls never see only part of them.
+
+  ## Options
+
+  - `kind` : Only undeclares entities of this kind, or of one of these kinds.
+  - `key_expr` : Only undeclares entities whose key expression is included in this one,
+    e.g. `"robot/1/**"`.
+
+  ## Examples
+
+      iex> Zenohex.Session.undeclare_all(session_id, kind: [:subscriber, :queryable], key_expr: "robot/1/**")
+      {:ok, 2}
+  """
+  @spec undeclare_all(session_id :: id(), undeclare_all_opts()) ::
+          {:ok, non_neg_integer()} | {:error, reason :: term()}
+  defdelegate undeclare_all(session_id, opts \\ []),
+    to: Zenohex.Nif,
+    as: :session_undeclare_all
+
+  @doc """
+  Declares `key_expr` on the session, and returns it as a `t:Zenohex.KeyExpr.declared/0`.
+
+  The declared key expression is accepted anywhere a key expression string is,
+  e.g. by `put/4`, `delete/3` or `declare_subscriber/4`.
+  Messages then carry a short numeric id instead of the full key expression,
+  which saves bandwidth for long keys used often.
+
+  See `Zenohex.KeyExpr.undeclare/1` to undeclare it.
+
+  ## Examples
+
+      iex> {:ok, key_expr} = Zenohex.Session.declare_keyexpr(session_id, "fleet/robot/1/pose")
+      iex> Zenohex.Session.put(session_id, key_expr, "payload")
+      :ok
+
+  > ### Important {: .info}
+  >
+  > The returned `key_expr` must be held for as long as it is in use.
+  > If it is not held and gets garbage-collected by the BEAM,
+  > the key expression will be undeclared once no entity uses it.
+  """
+  @spec declare_keyexpr(session_id :: id(), String.t()) ::
+          {:ok, Zenohex.KeyExpr.declared()} | {:error, reason :: term()}
+  defdelegate declare_keyexpr(session_id, key_expr),
+    to: Zenohex.Nif,
+    as: :session_declare_keyexpr
+
   @doc """
   Declares a publisher associated with the given session and `key_expr`.
 
@@ -324,7 +746,7 @@ defmodule Zenohex.Session do
   > If it is not held and gets garbage-collected by the BEAM,
   > the underlying publisher in Rust will be automatically dropped.
   """
-  @spec declare_publisher(session_id :: id(), String.t(), publisher_opts()) ::
+  @spec declare_publisher(session_id :: id(), Zenohex.KeyExpr.t(), publisher_opts()) ::
           {:ok, publisher_id :: Zenohex.Publisher.id()} | {:error, reason :: term()}
   defdelegate declare_publisher(session_id, key_expr, opts \\ []),
     to: Zenohex.Nif,
@@ -349,7 +771,7 @@ defmodule Zenohex.Session do
   > If it is not held and gets garbage-collected by the BEAM,
   > the underlying querier in Rust will be automatically dropped.
   """
-  @spec declare_querier(session_id :: id(), String.t(), querier_opts()) ::
+  @spec declare_querier(session_id :: id(), Zenohex.KeyExpr.t(), querier_opts()) ::
           {:ok, querier_id :: Zenohex.Querier.id()} | {:error, reason :: term()}
   defdelegate declare_querier(session_id, key_expr, opts \\ []),
     to: Zenohex.Nif,
@@ -372,7 +794,7 @@ defmodule Zenohex.Session do
   > If it is not held and gets garbage-collected by the BEAM,
   > the underlying subscriber in Rust will be automatically dropped.
   """
-  @spec declare_subscriber(session_id :: id(), String.t(), pid(), subscriber_opts()) ::
+  @spec declare_subscriber(session_id :: id(), Zenohex.KeyExpr.t(), pid(), subscriber_opts()) ::
           {:ok, subscriber_id :: Zenohex.Subscriber.id()} | {:error, reason :: term()}
   defdelegate declare_subscriber(session_id, key_expr, pid \\ self(), opts \\ []),
     to: Zenohex.Nif,
@@ -395,9 +817,148 @@ defmodule Zenohex.Session do
   > If it is not held and gets garbage-collected by the BEAM,
   > the underlying queryable in Rust will be automatically dropped.
   """
-  @spec declare_queryable(session_id :: id(), String.t(), pid(), queryable_opts()) ::
+  @spec declare_queryable(session_id :: id(), Zenohex.KeyExpr.t(), pid(), queryable_opts()) ::
           {:ok, queryable_id :: Zenohex.Queryable.id()} | {:error, reason :: term()}
   defdelegate declare_queryable(session_id, key_expr, pid \\ self(), opts \\ []),
     to: Zenohex.Nif,
     as: :session_declare_queryable
+
+  @doc """
+  Declares a pull-mode subscriber for the specified `key_expr`.
+
+  Samples are stored in the native `handler` instead of being sent to a process,
+  and are pulled with `Zenohex.Receiver` using the returned `subscriber_id`.
+
+  ## Parameters
+
+    - `session_id`: Identifier of the session returned by `open/0` or `open/1`.
+    - `key_expr`: Key expression to subscribe to.
+    - `handler`: `{:fifo, capacity}` or `{:ring, capacity}`. See `Zenohex.Receiver`.
+    - `opts`: Options for configuring the subscriber.
+
+  > ### Important {: .info}
+  >
+  > The returned `subscriber_id` must be held for as long as the subscriber is in use.
+  > If it is not held and gets garbage-collected by the BEAM,
+  > the underlying subscriber in Rust will be automatically dropped.
+  """
+  @spec declare_pull_subscriber(
+          session_id :: id(),
+          Zenohex.KeyExpr.t(),
+          Zenohex.Receiver.handler(),
+          subscriber_opts()
+        ) ::
+          {:ok, subscriber_id :: Zenohex.Subscriber.id()} | {:error, reason :: term()}
+  defdelegate declare_pull_subscriber(session_id, key_expr, handler, opts \\ []),
+    to: Zenohex.Nif,
+    as: :session_declare_pull_subscriber
+
+  @doc """
+  Declares a pull-mode queryable for the specified `key_expr`.
+
+  Queries are stored in the native `handler` instead of being sent to a process,
+  and are pulled with `Zenohex.Receiver` using the returned `queryable_id`.
+
+  ## Parameters
+
+    - `session_id`: Identifier of the session returned by `open/0` or `open/1`.
+    - `key_expr`: Key expression that the queryable will handle.
+    - `handler`: `{:fifo, capacity}` or `{:ring, capacity}`. See `Zenohex.Receiver`.
+    - `opts`: Options for configuring the queryable.
+
+  > ### Important {: .info}
+  >
+  > The returned `queryable_id` must be held for as long as the queryable is in use.
+  > If it is not held and gets garbage-collected by the BEAM,
+  > the underlying queryable in Rust will be automatically dropped.
+  """
+  @spec declare_pull_queryable(
+          session_id :: id(),
+          Zenohex.KeyExpr.t(),
+          Zenohex.Receiver.handler(),
+          queryable_opts()
+        ) ::
+          {:ok, queryable_id :: Zenohex.Queryable.id()} | {:error, reason :: term()}
+  defdelegate declare_pull_queryable(session_id, key_expr, handler, opts \\ []),
+    to: Zenohex.Nif,
+    as: :session_declare_pull_queryable
+
+  @doc """
+  Declares an advanced publisher associated with the given session and `key_expr`.
+
+  An advanced publisher is a publisher from zenoh-ext, which can cache its last samples
+  and let subscribers detect missed samples. It is used with the functions of
+  `Zenohex.Publisher`, like a plain publisher.
+
+  As for a plain publisher, `reliability` and the other QoS options are set here
+  and apply to every sample it publishes.
+  With `sample_miss_detection`, the `source_info` option of `Zenohex.Publisher.put/3`
+  and `Zenohex.Publisher.delete/2` is replaced by the sequence numbered one of the publisher.
+
+  see. https://docs.rs/zenoh-ext/latest/zenoh_ext/struct.AdvancedPublisher.html
+
+  ## Parameters
+
+    - `session_id`: Identifier of the session returned by `open/0` or `open/1`.
+    - `key_expr`: Key expression to publish under.
+    - `opts`: `t:publisher_opts/0` and `t:advanced_publisher_opts/0`.
+
+  ## Examples
+
+      iex> Zenohex.Session.declare_advanced_publisher(session_id, "key/expr",
+      ...>   cache: [max_samples: 10],
+      ...>   sample_miss_detection: {:heartbeat, 500},
+      ...>   publisher_detection: true
+      ...> )
+
+  > ### Important {: .info}
+  >
+  > The returned `publisher_id` must be held for as long as the publisher is in use.
+  > If it is not held and gets garbage-collected by the BEAM,
+  > the underlying publisher in Rust will be automatically dropped.
+  """
+  @spec declare_advanced_publisher(
+          session_id :: id(),
+          Zenohex.KeyExpr.t(),
+          publisher_opts() | advanced_publisher_opts()
+        ) ::
+          {:ok, publisher_id :: Zenohex.Publisher.id()} | {:error, reason :: term()}
+  defdelegate declare_advanced_publisher(session_id, key_expr, opts \\ []),
+    to: Zenohex.Nif,
+    as: :session_declare_advanced_publisher
+
+  @doc """
+  Declares an advanced subscriber for the specified `key_expr`.
+
+  An advanced subscriber is a subscriber from zenoh-ext, which can retrieve
+  the history of advanced publishers and recover missed samples.
+  It is undeclared with `Zenohex.Subscriber.undeclare/1`, like a plain subscriber.
+
+  see. https://docs.rs/zenoh-ext/latest/zenoh_ext/struct.AdvancedSubscriber.html
+
+  ## Parameters
+
+    - `session_id`: Identifier of the session returned by `open/0` or `open/1`.
+    - `key_expr`: Key expression to subscribe to.
+    - `pid`: Process to receive subscription messages. Defaults to the calling process.
+      - Samples are delivered as `Zenohex.Sample`.
+      - Samples that could not be recovered are notified as `Zenohex.SampleMiss`.
+    - `opts`: `t:subscriber_opts/0` and `t:advanced_subscriber_opts/0`.
+
+  > ### Important {: .info}
+  >
+  > The returned `subscriber_id` must be held for as long as the subscriber is in use.
+  > If it is not held and gets garbage-collected by the BEAM,
+  > the underlying subscriber in Rust will be automatically dropped.
+  """
+  @spec declare_advanced_subscriber(
+          session_id :: id(),
+          Zenohex.KeyExpr.t(),
+          pid(),
+          subscriber_opts() | advanced_subscriber_opts()
+        ) ::
+          {:ok, subscriber_id :: Zenohex.Subscriber.id()} | {:error, reason :: term()}
+  defdelegate declare_advanced_subscriber(session_id, key_expr, pid \\ self(), opts \\ []),
+    to: Zenohex.Nif,
+    as: :session_declare_advanced_subscriber
 end
@@ -0,0 +1,65 @@
+defmodule Zenohex.Shm do
+  @moduledoc """
+  Interface for publishing payloads through shared memory (SHM).
+
+  A provider owns a POSIX shared memory pool. Buffers allocated from the pool
+  can be filled with `write/3` and published with `Zenohex.Session.put/4` or
+  `Zenohex.Publisher.put/3`. Subscribers on the same host then receive the payload
+  without copying it through the network, and the received `Zenohex.Sample`
+  has `shm: true`.
+
+  A buffer is moved into zenoh when it is published, so it can be published only once.
+
+  see. https://docs.rs/zenoh/latest/zenoh/shm/index.html
+
+  ## Examples
+
+      iex> {:ok, provider} = Zenohex.Shm.provider_new(65_536)
+      iex> {:ok, buffer} = Zenohex.Shm.alloc(provider, 7)
+      iex> :ok = Zenohex.Shm.write(buffer, "payload")
+      iex> Zenohex.Session.put(session_id, "key/expr", buffer)
+      :ok
+
+  > #### Important {: .info}
+  >
+  > Keep the provider alive as long as its buffers are in use.
+  > The shared memory pool is released when the provider is garbage collected.
+  """
+
+  @type provider :: reference()
+  @type buffer :: reference()
+
+  @doc """
+  Creates a provider with a shared memory pool of `size` bytes.
+
+  `alignment` is the power of two of the allocation alignment,
+  e.g. `3` aligns allocations to 8 bytes.
+  """
+  @spec provider_new(non_neg_integer(), non_neg_integer()) ::
+          {:ok, provider()} | {:error, reason :: term()}
+  defdelegate provider_new(size, alignment \\ 0), to: Zenohex.Nif, as: :shm_provider_new
+
+  @doc """
+  Allocates a buffer of `size` bytes from the provider.
+
+  Returns an error instead of waiting when the pool has no free space.
+  """
+  @spec alloc(provider(), non_neg_integer()) :: {:ok, buffer()} | {:error, reason :: term()}
+  defdelegate alloc(provider, size), to: Zenohex.Nif, as: :shm_provider_alloc
+
+  @doc """
+  Writes `binary` into the buffer starting at `offset`.
+
+  Raises `ArgumentError` if the binary does not fit in the buffer.
+  """
+  @spec write(buffer(), binary(), non_neg_integer()) :: :ok | {:error, reason :: term()}
+  def write(buffer, binary, offset \\ 0) when is_binary(binary) do
+    Zenohex.Nif.shm_buffer_write(buffer, offset, binary)
+  end
+
+  @doc """
+  Returns the size of the buffer in bytes.
+  """
+  @spec len(buffer()) :: non_neg_integer()
+  defdelegate len(buffer), to: Zenohex.Nif, as: :shm_buffer_len
+end
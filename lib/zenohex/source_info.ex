@@ -0,0 +1,23 @@
+defmodule Zenohex.SourceInfo do
+  @moduledoc """
+  A struct that corresponds one-to-one to `zenoh::sample::SourceInfo`.
+
+  Identifies the entity which published a sample or sent a query,
+  and the sequence number of the sample or query from that entity.
+
+  Received as `source_info` of `Zenohex.Sample` and `Zenohex.Query`,
+  and attached to publications, replies and queries with the `:source_info` option.
+  zenoh attaches no source info by default.
+
+  - `source_id` : Global ID of the source entity.
+  - `source_sn` : Sequence number of the sample or query from the source entity.
+
+  see. https://docs.rs/zenoh/latest/zenoh/sample/struct.SourceInfo.html
+  """
+
+  @type t :: %__MODULE__{
+          source_id: Zenohex.EntityGlobalId.t(),
+          source_sn: non_neg_integer()
+        }
+  defstruct [:source_id, :source_sn]
+end
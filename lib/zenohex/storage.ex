@@ -0,0 +1,79 @@
+defmodule Zenohex.Storage do
+  @moduledoc """
+  Interface for in-memory storages, which answer queries without involving the BEAM.
+
+  A storage subscribes to a key expression, keeps the latest sample per key inside the NIF,
+  and replies to the queries intersecting it directly from Rust.
+  This avoids the cost of answering each `Zenohex.Query` from an Elixir process.
+
+  Samples are ordered by their timestamp, when they have one.
+  A sample older than the stored one for the same key is ignored,
+  and a delete sample removes the key.
+  A timestamped delete is kept as a tombstone for `:tombstone_lifespan`,
+  so that an older put received late does not bring the key back.
+
+  The contents of a storage can still be read, listed and purged from Elixir.
+  Like `Zenohex.KeyExpr.Tree`, these functions raise `ArgumentError`
+  if given an invalid key expression.
+
+  ## Examples
+
+      iex> {:ok, storage} = Zenohex.Storage.declare(session_id, "demo/**")
+      iex> :ok = Zenohex.Session.put(session_id, "demo/a", "a")
+      iex> Zenohex.Session.get(session_id, "demo/**", 100)
+      {:ok, [%Zenohex.Sample{key_expr: "demo/a", payload: "a"}]}
+      iex> Zenohex.Storage.keys(storage)
+      {:ok, ["demo/a"]}
+  """
+
+  @type storage :: reference()
+
+  @type declare_opts :: [
+          allowed_origin: Zenohex.Session.locality(),
+          complete: boolean(),
+          tombstone_lifespan: non_neg_integer()
+        ]
+
+  @doc """
+  Declares a storage for `key_expr`.
+
+  `:allowed_origin` applies to both the samples stored and the queries answered.
+  With `complete: true`, the storage claims to hold all the values under `key_expr`.
+  `:tombstone_lifespan` is in milliseconds, and defaults to one day
+  like the garbage collection of zenoh-plugin-storage-manager.
+
+  > ### Important {: .info}
+  >
+  > The returned `storage` must be held for as long as the storage is in use.
+  > If it is not held and gets garbage-collected by the BEAM,
+  > the underlying storage in Rust will be automatically dropped.
+  """
+  @spec declare(Zenohex.Session.id(), Zenohex.KeyExpr.t(), declare_opts()) ::
+          {:ok, storage()} | {:error, reason :: term()}
+  defdelegate declare(session_id, key_expr, opts \\ []), to: Zenohex.Nif, as: :storage_declare
+
+  @doc """
+  Undeclares the storage. Its contents are kept, and can still be read.
+  """
+  @spec undeclare(storage()) :: :ok | {:error, reason :: term()}
+  defdelegate undeclare(storage), to: Zenohex.Nif, as: :storage_undeclare
+
+  @doc """
+  Returns the stored samples whose key intersects `key_expr`.
+  """
+  @spec get(storage(), String.t()) :: {:ok, [Zenohex.Sample.t()]} | {:error, reason :: term()}
+  defdelegate get(storage, key_expr \\ "**"), to: Zenohex.Nif, as: :storage_get
+
+  @doc """
+  Returns the keys of the stored samples.
+  """
+  @spec keys(storage()) :: {:ok, [String.t()]} | {:error, reason :: term()}
+  defdelegate keys(storage), to: Zenohex.Nif, as: :storage_keys
+
+  @doc """
+  Removes the stored samples whose key intersects `key_expr`,
+  and returns how many were removed.
+  """
+  @spec purge(storage(), String.t()) :: {:ok, non_neg_integer()} | {:error, reason :: term()}
+  defdelegate purge(storage, key_expr \\ "**"), to: Zenohex.Nif, as: :storage_purge
+end
@@ -0,0 +1,79 @@
+defmodule Zenohex.Timestamp do
+  @moduledoc """
+  A struct that corresponds one-to-one to `zenoh::time::Timestamp`.
+
+  A timestamp generated by a Hybrid Logical Clock (HLC),
+
+  - `time` : The raw NTP64 time, the upper 32 bits are seconds since the UNIX epoch
+    and the lower 32 bits are the fraction of a second.
+  - `id` : The HLC id as a hexadecimal string, which is the Zenoh ID of the session by default.
+
+  Timestamps are totally ordered, first by `time` and then by `id`.
+  Use `compare/2` rather than comparing structs with `<` or `>`.
+
+  see. https://docs.rs/zenoh/latest/zenoh/time/struct.Timestamp.html
+
+  ## Examples
+
+      iex> {:ok, timestamp} = Zenohex.Session.new_timestamp(session_id)
+      iex> Zenohex.Timestamp.to_string(timestamp)
+      "2025-07-16T01:34:56.871273403Z/208a2ec783ec4527a39cc1d5559c70e9"
+  """
+
+  @type t :: %__MODULE__{
+          time: non_neg_integer(),
+          id: String.t()
+        }
+  @enforce_keys [:time, :id]
+  defstruct [:time, :id]
+
+  @doc """
+  Compares two timestamps, following the total order of the HLC.
+
+  Can be used with `Enum.sort/2`, e.g. `Enum.sort(timestamps, Zenohex.Timestamp)`.
+  """
+  @spec compare(t(), t()) :: :lt | :eq | :gt
+  defdelegate compare(timestamp1, timestamp2), to: Zenohex.Nif, as: :timestamp_compare
+
+  @doc """
+  Converts the timestamp to a `DateTime` in UTC.
+
+  `DateTime` has microsecond precision, so the sub-microsecond part of `time` is truncated.
+  """
+  @spec to_datetime(t()) :: DateTime.t()
+  def to_datetime(%__MODULE__{time: time}) do
+    time
+    |> Zenohex.Nif.timestamp_time_to_unix_nanos()
+    |> DateTime.from_unix!(:nanosecond)
+  end
+
+  @doc """
+  Creates a timestamp from a `DateTime` and an HLC id.
+
+  The NTP64 time of a timestamp counts 32-bit seconds since the UNIX epoch,
+  so an error is returned for a `datetime` before 1970 or after 2106-02-07T06:28:15Z.
+  """
+  @spec from_datetime(DateTime.t(), String.t()) :: {:ok, t()} | {:error, reason :: term()}
+  def from_datetime(%DateTime{} = datetime, id) when is_binary(id) do
+    with {:ok, time} <-
+           datetime
+           |> DateTime.to_unix(:nanosecond)
+           |> Zenohex.Nif.timestamp_time_from_unix_nanos() do
+      {:ok, %__MODULE__{time: time, id: id}}
+    end
+  end
+
+  @doc """
+  Formats the timestamp as `"<RFC3339>/<hlc_id_hexadecimal>"`.
+
+  The RFC3339 part has nanosecond precision, so the conversion is lossy.
+  """
+  @spec to_string(t()) :: String.t()
+  defdelegate to_string(timestamp), to: Zenohex.Nif, as: :timestamp_to_string
+
+  @doc """
+  Parses a timestamp formatted as `"<RFC3339>/<hlc_id_hexadecimal>"`.
+  """
+  @spec parse(String.t()) :: {:ok, t()} | {:error, reason :: term()}
+  defdelegate parse(string), to: Zenohex.Nif, as: :timestamp_parse
+end
@@ -0,0 +1,39 @@
+defmodule Zenohex.ZenohId do
+  @moduledoc """
+  Functions for Zenoh IDs, which correspond to `zenoh::session::ZenohId`.
+
+  A Zenoh ID is the global unique id of a Zenoh session,
+  represented as a lowercase hexadecimal string.
+
+  see. https://docs.rs/zenoh/latest/zenoh/session/struct.ZenohId.html
+  """
+
+  @type t :: String.t()
+
+  @doc """
+  Validates a Zenoh ID and returns it in its canonical form.
+
+  Uppercase hexadecimal digits are not accepted.
+
+  ## Examples
+
+      iex> Zenohex.ZenohId.parse("208a2ec783ec4527a39cc1d5559c70e9")
+      {:ok, "208a2ec783ec4527a39cc1d5559c70e9"}
+  """
+  @spec parse(String.t()) :: {:ok, t()} | {:error, reason :: term()}
+  defdelegate parse(string), to: Zenohex.Nif, as: :zenoh_id_parse
+
+  @doc """
+  Compares two Zenoh IDs, following the order of `zenoh::session::ZenohId`.
+
+  Can be used with `Enum.sort/2`, e.g. `Enum.sort(zids, Zenohex.ZenohId)`.
+  Raises `ArgumentError` if either id is invalid.
+  """
+  @spec compare(t(), t()) :: :lt | :eq | :gt
+  def compare(zid1, zid2) do
+    case Zenohex.Nif.zenoh_id_compare(zid1, zid2) do
+      {:error, reason} -> raise ArgumentError, "invalid Zenoh ID: #{inspect(reason)}"
+      order -> order
+    end
+  end
+end
@@ -0,0 +1,321 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::Duration;
+use std::time::Instant;
+
+use zenoh::Wait;
+
+#[derive(Clone, Copy, PartialEq, rustler::NifUnitEnum)]
+pub enum AdminEntityKind {
+    Publisher,
+    Querier,
+    Subscriber,
+    Queryable,
+    Token,
+}
+
+impl AdminEntityKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AdminEntityKind::Publisher => "publisher",
+            AdminEntityKind::Querier => "querier",
+            AdminEntityKind::Subscriber => "subscriber",
+            AdminEntityKind::Queryable => "queryable",
+            AdminEntityKind::Token => "token",
+        }
+    }
+}
+
+#[derive(rustler::NifStruct)]
+#[module = "Zenohex.Admin.Link"]
+struct ZenohexAdminLink {
+    src: String,
+    dst: String,
+}
+
+#[derive(rustler::NifStruct)]
+#[module = "Zenohex.Admin.Neighbor"]
+struct ZenohexAdminNeighbor {
+    zid: String,
+    whatami: Option<crate::config::WhatAmI>,
+    links: Vec<ZenohexAdminLink>,
+}
+
+#[derive(rustler::NifStruct)]
+#[module = "Zenohex.Admin.Node"]
+struct ZenohexAdminNode {
+    zid: String,
+    whatami: crate::config::WhatAmI,
+    version: Option<String>,
+    locators: Vec<String>,
+    neighbors: Vec<ZenohexAdminNeighbor>,
+}
+
+#[derive(rustler::NifStruct)]
+#[module = "Zenohex.Admin.Entity"]
+struct ZenohexAdminEntity {
+    zid: String,
+    whatami: crate::config::WhatAmI,
+    kind: AdminEntityKind,
+    key_expr: String,
+}
+
+#[derive(rustler::NifStruct)]
+#[module = "Zenohex.Admin.Edge"]
+struct ZenohexAdminEdge {
+    from: String,
+    to: String,
+    weight: Option<f64>,
+}
+
+#[derive(rustler::NifStruct)]
+#[module = "Zenohex.Admin.Graph"]
+struct ZenohexAdminGraph {
+    zid: String,
+    whatami: crate::config::WhatAmI,
+    name: String,
+    nodes: Vec<String>,
+    edges: Vec<ZenohexAdminEdge>,
+}
+
+// An admin space reply, whose key expression is `@/<zid>/<whatami>[/<rest>]`.
+pub struct AdminReply {
+    pub zid: String,
+    pub whatami: crate::config::WhatAmI,
+    pub rest: Option<String>,
+    pub payload: Vec<u8>,
+}
+
+impl AdminReply {
+    fn parse(key_expr: &str, payload: Vec<u8>) -> Option<Self> {
+        let mut chunks = key_expr.strip_prefix("@/")?.splitn(3, '/');
+        let zid = chunks.next()?.to_string();
+        let whatami = zenoh::config::WhatAmI::from_str(chunks.next()?).ok()?;
+
+        Some(AdminReply {
+            zid,
+            whatami: whatami.into(),
+            rest: chunks.next().map(String::from),
+            payload,
+        })
+    }
+}
+
+pub fn admin_get(
+    session_id: &zenoh::session::ZenohId,
+    selector: &str,
+    timeout: u64,
+) -> rustler::NifResult<Vec<AdminReply>> {
+    let session =
+        crate::session::SessionMap::get_session(&crate::session::SESSION_MAP, session_id)?;
+    // WHY: Keep the read lock only around handler creation, same as `session_get`.
+    let channel_handler = {
+        let session_locked = session.read().unwrap();
+
+        session_locked
+            .get(selector)
+            .target(zenoh::query::QueryTarget::All)
+            .consolidation(zenoh::query::ConsolidationMode::None)
+            .timeout(Duration::from_millis(timeout))
+            .wait()
+            .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?
+    };
+
+    let deadline = Instant::now() + Duration::from_millis(timeout);
+    let mut replies = Vec::new();
+
+    // NOTE: Unlike `session_get`, a timeout is not an error,
+    //       since remote nodes may or may not expose their admin space.
+    while let Ok(Some(reply)) = channel_handler.recv_deadline(deadline) {
+        if let Ok(sample) = reply.result() {
+            let payload = sample.payload().to_bytes().to_vec();
+
+            if let Some(admin_reply) = AdminReply::parse(sample.key_expr().as_str(), payload) {
+                replies.push(admin_reply);
+            }
+        }
+    }
+
+    Ok(replies)
+}
+
+fn json_string(value: &serde_json::Value, key: &str) -> Option<String> {
+    value.get(key)?.as_str().map(String::from)
+}
+
+fn json_array<'a>(value: &'a serde_json::Value, key: &str) -> &'a [serde_json::Value] {
+    value
+        .get(key)
+        .and_then(serde_json::Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+impl ZenohexAdminNode {
+    fn from(reply: AdminReply) -> Option<Self> {
+        let json: serde_json::Value = serde_json::from_slice(&reply.payload).ok()?;
+
+        let locators = json_array(&json, "locators")
+            .iter()
+            .filter_map(|locator| locator.as_str().map(String::from))
+            .collect();
+
+        // NOTE: The admin space calls the transports of a node "sessions".
+        let neighbors = json_array(&json, "sessions")
+            .iter()
+            .filter_map(|session| {
+                let links = json_array(session, "links")
+                    .iter()
+                    .filter_map(|link| {
+                        Some(ZenohexAdminLink {
+                            src: json_string(link, "src")?,
+                            dst: json_string(link, "dst")?,
+                        })
+                    })
+                    .collect();
+
+                Some(ZenohexAdminNeighbor {
+                    zid: json_string(session, "peer")?,
+                    whatami: json_string(session, "whatami")
+                        .and_then(|whatami| zenoh::config::WhatAmI::from_str(&whatami).ok())
+                        .map(crate::config::WhatAmI::from),
+                    links,
+                })
+            })
+            .collect();
+
+        Some(ZenohexAdminNode {
+            zid: reply.zid,
+            whatami: reply.whatami,
+            version: json_string(&json, "version"),
+            locators,
+            neighbors,
+        })
+    }
+}
+
+impl ZenohexAdminGraph {
+    // Parses the graphviz dot format of petgraph, e.g.
+    //
+    //     graph {
+    //         0 [ label = "208a2ec783ec4527a39cc1d5559c70e9" ]
+    //         1 [ label = "f1b2c3d4e5f60718293a4b5c6d7e8f90" ]
+    //         0 -- 1 [ label = "100.0" ]
+    //     }
+    fn from(reply: AdminReply, name: String) -> Option<Self> {
+        let dot = String::from_utf8(reply.payload).ok()?;
+
+        let mut nodes = HashMap::new();
+        let mut edge_lines = Vec::new();
+
+        for line in dot.lines().map(str::trim) {
+            let Some((head, attributes)) = line.split_once('[') else {
+                continue;
+            };
+            let label = attributes
+                .split_once("label = \"")
+                .and_then(|(_, label)| label.split_once('"'))
+                .map(|(label, _)| label.to_string());
+
+            let head: Vec<&str> = head.split_whitespace().collect();
+            match (head.as_slice(), label) {
+                ([index], Some(label)) => {
+                    nodes.insert(index.to_string(), label);
+                }
+                ([from, "--" | "->", to], label) => {
+                    edge_lines.push((from.to_string(), to.to_string(), label));
+                }
+                _ => continue,
+            }
+        }
+
+        let edges = edge_lines
+            .into_iter()
+            .filter_map(|(from, to, label)| {
+                Some(ZenohexAdminEdge {
+                    from: nodes.get(&from)?.clone(),
+                    to: nodes.get(&to)?.clone(),
+                    weight: label.and_then(|label| label.parse().ok()),
+                })
+            })
+            .collect();
+
+        Some(ZenohexAdminGraph {
+            zid: reply.zid,
+            whatami: reply.whatami,
+            name,
+            nodes: nodes.into_values().collect(),
+            edges,
+        })
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn admin_nodes(
+    session_id_resource: rustler::ResourceArc<crate::session::SessionIdResource>,
+    timeout: u64,
+) -> rustler::NifResult<(rustler::Atom, Vec<ZenohexAdminNode>)> {
+    let replies = admin_get(&session_id_resource, "@/*/*", timeout)?;
+
+    let nodes = replies
+        .into_iter()
+        .filter(|reply| reply.rest.is_none())
+        .filter_map(ZenohexAdminNode::from)
+        .collect();
+
+    Ok((rustler::types::atom::ok(), nodes))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn admin_entities(
+    session_id_resource: rustler::ResourceArc<crate::session::SessionIdResource>,
+    kind: AdminEntityKind,
+    timeout: u64,
+) -> rustler::NifResult<(rustler::Atom, Vec<ZenohexAdminEntity>)> {
+    let selector = format!("@/*/*/{}/**", kind.as_str());
+    let replies = admin_get(&session_id_resource, &selector, timeout)?;
+
+    let entities = replies
+        .into_iter()
+        .filter_map(|reply| {
+            let key_expr = reply
+                .rest
+                .as_deref()?
+                .strip_prefix(kind.as_str())?
+                .strip_prefix('/')?
+                .to_string();
+
+            Some(ZenohexAdminEntity {
+                zid: reply.zid,
+                whatami: reply.whatami,
+                kind,
+                key_expr,
+            })
+        })
+        .collect();
+
+    Ok((rustler::types::atom::ok(), entities))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn admin_linkstate(
+    session_id_resource: rustler::ResourceArc<crate::session::SessionIdResource>,
+    timeout: u64,
+) -> rustler::NifResult<(rustler::Atom, Vec<ZenohexAdminGraph>)> {
+    let replies = admin_get(&session_id_resource, "@/*/*/linkstate/*", timeout)?;
+
+    let graphs = replies
+        .into_iter()
+        .filter_map(|reply| {
+            let name = reply
+                .rest
+                .as_deref()?
+                .strip_prefix("linkstate/")?
+                .to_string();
+
+            ZenohexAdminGraph::from(reply, name)
+        })
+        .collect();
+
+    Ok((rustler::types::atom::ok(), graphs))
+}
@@ -66,6 +66,30 @@ impl From<zenoh::qos::Priority> for Priority {
     }
 }
 
+#[derive(rustler::NifUnitEnum)]
+pub enum Reliability {
+    BestEffort,
+    Reliable,
+}
+
+impl From<Reliability> for zenoh::qos::Reliability {
+    fn from(value: Reliability) -> Self {
+        match value {
+            Reliability::BestEffort => zenoh::qos::Reliability::BestEffort,
+            Reliability::Reliable => zenoh::qos::Reliability::Reliable,
+        }
+    }
+}
+
+impl From<zenoh::qos::Reliability> for Reliability {
+    fn from(value: zenoh::qos::Reliability) -> Self {
+        match value {
+            zenoh::qos::Reliability::BestEffort => Reliability::BestEffort,
+            zenoh::qos::Reliability::Reliable => Reliability::Reliable,
+        }
+    }
+}
+
 #[derive(rustler::NifUnitEnum)]
 enum QueryTarget {
     BestMatching,
@@ -140,6 +164,13 @@ pub trait Builder: Sized {
         Self: Sized;
 }
 
+fn decode_source_info(v: rustler::Term) -> rustler::NifResult<Option<zenoh::sample::SourceInfo>> {
+    match v.decode::<Option<crate::sample::ZenohexSourceInfo>>()? {
+        Some(source_info) => Ok(Some(zenoh::sample::SourceInfo::try_from(source_info)?)),
+        None => Ok(None),
+    }
+}
+
 impl Builder
     for zenoh::pubsub::PublicationBuilder<
         zenoh::pubsub::PublisherBuilder<'_, '_>,
@@ -175,13 +206,19 @@ impl Builder
                     let priority = v.decode::<Priority>()?;
                     Ok(builder.priority(priority.into()))
                 }
+                k if k == crate::atoms::reliability() => {
+                    let reliability = v.decode::<Reliability>()?;
+                    Ok(builder.reliability(reliability.into()))
+                }
+                k if k == crate::atoms::source_info() => match decode_source_info(v)? {
+                    Some(source_info) => Ok(builder.source_info(source_info)),
+                    None => Ok(builder),
+                },
                 k if k == crate::atoms::timestamp() => {
-                    if let Some(timestamp) = v.decode::<Option<String>>()? {
-                        let timestamp =
-                            zenoh::time::Timestamp::parse_rfc3339(&timestamp).map_err(|error| {
-                                rustler::Error::Term(crate::zenoh_error!(error.cause))
-                            })?;
-                        Ok(builder.timestamp(timestamp))
+                    if let Some(timestamp) =
+                        v.decode::<Option<crate::timestamp::ZenohexTimestamp>>()?
+                    {
+                        Ok(builder.timestamp(zenoh::time::Timestamp::try_from(timestamp)?))
                     } else {
                         Ok(builder)
                     }
@@ -223,13 +260,19 @@ impl Builder
                     let priority = v.decode::<Priority>()?;
                     Ok(builder.priority(priority.into()))
                 }
+                k if k == crate::atoms::reliability() => {
+                    let reliability = v.decode::<Reliability>()?;
+                    Ok(builder.reliability(reliability.into()))
+                }
+                k if k == crate::atoms::source_info() => match decode_source_info(v)? {
+                    Some(source_info) => Ok(builder.source_info(source_info)),
+                    None => Ok(builder),
+                },
                 k if k == crate::atoms::timestamp() => {
-                    if let Some(timestamp) = v.decode::<Option<String>>()? {
-                        let timestamp =
-                            zenoh::time::Timestamp::parse_rfc3339(&timestamp).map_err(|error| {
-                                rustler::Error::Term(crate::zenoh_error!(error.cause))
-                            })?;
-                        Ok(builder.timestamp(timestamp))
+                    if let Some(timestamp) =
+                        v.decode::<Option<crate::timestamp::ZenohexTimestamp>>()?
+                    {
+                        Ok(builder.timestamp(zenoh::time::Timestamp::try_from(timestamp)?))
                     } else {
                         Ok(builder)
                     }
@@ -263,13 +306,15 @@ impl Builder
                     let encoding = v.decode::<&str>()?;
                     Ok(builder.encoding(encoding))
                 }
+                k if k == crate::atoms::source_info() => match decode_source_info(v)? {
+                    Some(source_info) => Ok(builder.source_info(source_info)),
+                    None => Ok(builder),
+                },
                 k if k == crate::atoms::timestamp() => {
-                    if let Some(timestamp) = v.decode::<Option<String>>()? {
-                        let timestamp =
-                            zenoh::time::Timestamp::parse_rfc3339(&timestamp).map_err(|error| {
-                                rustler::Error::Term(crate::zenoh_error!(error.cause))
-                            })?;
-                        Ok(builder.timestamp(timestamp))
+                    if let Some(timestamp) =
+                        v.decode::<Option<crate::timestamp::ZenohexTimestamp>>()?
+                    {
+                        Ok(builder.timestamp(zenoh::time::Timestamp::try_from(timestamp)?))
                     } else {
                         Ok(builder)
                     }
@@ -299,13 +344,15 @@ impl Builder
                         Ok(builder)
                     }
                 }
+                k if k == crate::atoms::source_info() => match decode_source_info(v)? {
+                    Some(source_info) => Ok(builder.source_info(source_info)),
+                    None => Ok(builder),
+                },
                 k if k == crate::atoms::timestamp() => {
-                    if let Some(timestamp) = v.decode::<Option<String>>()? {
-                        let timestamp =
-                            zenoh::time::Timestamp::parse_rfc3339(&timestamp).map_err(|error| {
-                                rustler::Error::Term(crate::zenoh_error!(error.cause))
-                            })?;
-                        Ok(builder.timestamp(timestamp))
+                    if let Some(timestamp) =
+                        v.decode::<Option<crate::timestamp::ZenohexTimestamp>>()?
+                    {
+                        Ok(builder.timestamp(zenoh::time::Timestamp::try_from(timestamp)?))
                     } else {
                         Ok(builder)
                     }
@@ -338,6 +385,12 @@ impl Builder for zenoh::session::SessionGetBuilder<'_, '_, zenoh::handlers::Defa
                     let allowed_destination = v.decode::<Locality>()?;
                     Ok(builder.allowed_destination(allowed_destination.into()))
                 }
+                k if k == crate::atoms::cancellation_token() => {
+                    let cancellation_token: rustler::ResourceArc<
+                        crate::cancellation::CancellationTokenResource,
+                    > = v.decode()?;
+                    Ok(builder.cancellation_token((**cancellation_token).clone()))
+                }
                 k if k == crate::atoms::congestion_control() => {
                     let congestion_control = v.decode::<CongestionControl>()?;
                     Ok(builder.congestion_control(congestion_control.into()))
@@ -366,6 +419,10 @@ impl Builder for zenoh::session::SessionGetBuilder<'_, '_, zenoh::handlers::Defa
                     let priority = v.decode::<Priority>()?;
                     Ok(builder.priority(priority.into()))
                 }
+                k if k == crate::atoms::source_info() => match decode_source_info(v)? {
+                    Some(source_info) => Ok(builder.source_info(source_info)),
+                    None => Ok(builder),
+                },
                 k if k == crate::atoms::target() => {
                     let target = v.decode::<QueryTarget>()?;
                     Ok(builder.target(target.into()))
@@ -407,6 +464,10 @@ impl Builder for zenoh::pubsub::PublisherBuilder<'_, '_> {
                     let priority = v.decode::<Priority>()?;
                     Ok(builder.priority(priority.into()))
                 }
+                k if k == crate::atoms::reliability() => {
+                    let reliability = v.decode::<Reliability>()?;
+                    Ok(builder.reliability(reliability.into()))
+                }
                 _ => Ok(builder),
             }
         })
@@ -511,6 +572,12 @@ impl Builder for zenoh::query::QuerierGetBuilder<'_, '_, zenoh::handlers::Defaul
                         Ok(builder)
                     }
                 }
+                k if k == crate::atoms::cancellation_token() => {
+                    let cancellation_token: rustler::ResourceArc<
+                        crate::cancellation::CancellationTokenResource,
+                    > = v.decode()?;
+                    Ok(builder.cancellation_token((**cancellation_token).clone()))
+                }
                 k if k == crate::atoms::encoding() => {
                     let encoding = v.decode::<String>()?;
                     Ok(builder.encoding(encoding))
@@ -526,6 +593,10 @@ impl Builder for zenoh::query::QuerierGetBuilder<'_, '_, zenoh::handlers::Defaul
                         Ok(builder)
                     }
                 }
+                k if k == crate::atoms::source_info() => match decode_source_info(v)? {
+                    Some(source_info) => Ok(builder.source_info(source_info)),
+                    None => Ok(builder),
+                },
                 _ => Ok(builder),
             }
         })
@@ -554,13 +625,15 @@ impl Builder for zenoh::query::ReplyBuilder<'_, '_, zenoh::query::ReplyBuilderPu
                     let express = v.decode()?;
                     Ok(builder.express(express))
                 }
+                k if k == crate::atoms::source_info() => match decode_source_info(v)? {
+                    Some(source_info) => Ok(builder.source_info(source_info)),
+                    None => Ok(builder),
+                },
                 k if k == crate::atoms::timestamp() => {
-                    if let Some(timestamp) = v.decode::<Option<String>>()? {
-                        let timestamp =
-                            zenoh::time::Timestamp::parse_rfc3339(&timestamp).map_err(|error| {
-                                rustler::Error::Term(crate::zenoh_error!(error.cause))
-                            })?;
-                        Ok(builder.timestamp(timestamp))
+                    if let Some(timestamp) =
+                        v.decode::<Option<crate::timestamp::ZenohexTimestamp>>()?
+                    {
+                        Ok(builder.timestamp(zenoh::time::Timestamp::try_from(timestamp)?))
                     } else {
                         Ok(builder)
                     }
@@ -606,13 +679,15 @@ impl Builder for zenoh::query::ReplyBuilder<'_, '_, zenoh::query::ReplyBuilderDe
                     let express = v.decode()?;
                     Ok(builder.express(express))
                 }
+                k if k == crate::atoms::source_info() => match decode_source_info(v)? {
+                    Some(source_info) => Ok(builder.source_info(source_info)),
+                    None => Ok(builder),
+                },
                 k if k == crate::atoms::timestamp() => {
-                    if let Some(timestamp) = v.decode::<Option<String>>()? {
-                        let timestamp =
-                            zenoh::time::Timestamp::parse_rfc3339(&timestamp).map_err(|error| {
-                                rustler::Error::Term(crate::zenoh_error!(error.cause))
-                            })?;
-                        Ok(builder.timestamp(timestamp))
+                    if let Some(timestamp) =
+                        v.decode::<Option<crate::timestamp::ZenohexTimestamp>>()?
+                    {
+                        Ok(builder.timestamp(zenoh::time::Timestamp::try_from(timestamp)?))
                     } else {
                         Ok(builder)
                     }
@@ -630,6 +705,12 @@ impl Builder for zenoh::liveliness::LivelinessGetBuilder<'_, '_, zenoh::handlers
         opts_iter.try_fold(self, |builder, opt| {
             let (k, v): (rustler::Atom, rustler::Term) = opt.decode()?;
             match k {
+                k if k == crate::atoms::cancellation_token() => {
+                    let cancellation_token: rustler::ResourceArc<
+                        crate::cancellation::CancellationTokenResource,
+                    > = v.decode()?;
+                    Ok(builder.cancellation_token((**cancellation_token).clone()))
+                }
                 k if k == crate::atoms::query_timeout() => {
                     let query_timeout = v.decode::<u64>()?;
                     Ok(builder.timeout(Duration::from_millis(query_timeout)))
@@ -658,3 +739,367 @@ impl Builder
         })
     }
 }
+
+// NOTE: Options of the zenoh-ext advanced publisher and subscriber are given as keyword lists,
+//       e.g. `cache: [max_samples: 10]` or `history: [detect_late_publishers: true]`.
+//       `true` enables an option with the zenoh-ext defaults, `false` leaves it disabled.
+fn decode_cache_config(v: rustler::Term) -> rustler::NifResult<Option<zenoh_ext::CacheConfig>> {
+    if let Ok(enabled) = v.decode::<bool>() {
+        return Ok(enabled.then(zenoh_ext::CacheConfig::default));
+    }
+
+    let mut opts_iter: rustler::ListIterator = v.decode()?;
+
+    let config = opts_iter.try_fold(zenoh_ext::CacheConfig::default(), |config, opt| {
+        let (k, v): (rustler::Atom, rustler::Term) = opt.decode()?;
+        match k {
+            k if k == crate::atoms::max_samples() => {
+                let max_samples = v.decode::<usize>()?;
+                Ok(config.max_samples(max_samples))
+            }
+            _ => Ok(config),
+        }
+    })?;
+
+    Ok(Some(config))
+}
+
+#[derive(rustler::NifTaggedEnum)]
+enum SampleMissDetection {
+    Heartbeat(u64),
+    SporadicHeartbeat(u64),
+}
+
+fn decode_sample_miss_detection(
+    v: rustler::Term,
+) -> rustler::NifResult<Option<zenoh_ext::MissDetectionConfig>> {
+    if let Ok(enabled) = v.decode::<bool>() {
+        return Ok(enabled.then(zenoh_ext::MissDetectionConfig::default));
+    }
+
+    let config = match v.decode::<SampleMissDetection>()? {
+        SampleMissDetection::Heartbeat(period) => {
+            zenoh_ext::MissDetectionConfig::default().heartbeat(Duration::from_millis(period))
+        }
+        SampleMissDetection::SporadicHeartbeat(period) => zenoh_ext::MissDetectionConfig::default()
+            .sporadic_heartbeat(Duration::from_millis(period)),
+    };
+
+    Ok(Some(config))
+}
+
+fn decode_history_config(v: rustler::Term) -> rustler::NifResult<Option<zenoh_ext::HistoryConfig>> {
+    if let Ok(enabled) = v.decode::<bool>() {
+        return Ok(enabled.then(zenoh_ext::HistoryConfig::default));
+    }
+
+    let mut opts_iter: rustler::ListIterator = v.decode()?;
+
+    let config = opts_iter.try_fold(zenoh_ext::HistoryConfig::default(), |config, opt| {
+        let (k, v): (rustler::Atom, rustler::Term) = opt.decode()?;
+        match k {
+            k if k == crate::atoms::detect_late_publishers() => {
+                if v.decode::<bool>()? {
+                    Ok(config.detect_late_publishers())
+                } else {
+                    Ok(config)
+                }
+            }
+            k if k == crate::atoms::max_age() => {
+                // NOTE: zenoh-ext takes the max age in seconds.
+                let max_age = v.decode::<u64>()? as f64 / 1000.0;
+                Ok(config.max_age(max_age))
+            }
+            k if k == crate::atoms::max_samples() => {
+                let max_samples = v.decode::<usize>()?;
+                Ok(config.max_samples(max_samples))
+            }
+            _ => Ok(config),
+        }
+    })?;
+
+    Ok(Some(config))
+}
+
+#[derive(rustler::NifTaggedEnum)]
+enum Recovery {
+    Heartbeat,
+    PeriodicQueries(u64),
+}
+
+fn decode_recovery_config(
+    v: rustler::Term,
+) -> rustler::NifResult<Option<zenoh_ext::RecoveryConfig<true>>> {
+    if let Ok(enabled) = v.decode::<bool>() {
+        return Ok(enabled.then(|| zenoh_ext::RecoveryConfig::default().heartbeat()));
+    }
+
+    let config = match v.decode::<Recovery>()? {
+        Recovery::Heartbeat => zenoh_ext::RecoveryConfig::default().heartbeat(),
+        Recovery::PeriodicQueries(period) => {
+            zenoh_ext::RecoveryConfig::default().periodic_queries(Duration::from_millis(period))
+        }
+    };
+
+    Ok(Some(config))
+}
+
+impl Builder for zenoh_ext::AdvancedPublisherBuilder<'_, '_, '_> {
+    fn apply_opts(self, opts: rustler::Term) -> rustler::NifResult<Self> {
+        let mut opts_iter: rustler::ListIterator = opts.decode()?;
+
+        opts_iter.try_fold(self, |builder, opt| {
+            let (k, v): (rustler::Atom, rustler::Term) = opt.decode()?;
+            match k {
+                k if k == crate::atoms::cache() => match decode_cache_config(v)? {
+                    Some(config) => Ok(builder.cache(config)),
+                    None => Ok(builder),
+                },
+                k if k == crate::atoms::publisher_detection() => {
+                    if v.decode::<bool>()? {
+                        Ok(builder.publisher_detection())
+                    } else {
+                        Ok(builder)
+                    }
+                }
+                k if k == crate::atoms::sample_miss_detection() => {
+                    match decode_sample_miss_detection(v)? {
+                        Some(config) => Ok(builder.sample_miss_detection(config)),
+                        None => Ok(builder),
+                    }
+                }
+                _ => Ok(builder),
+            }
+        })
+    }
+}
+
+impl Builder for zenoh_ext::AdvancedSubscriberBuilder<'_, '_, '_, zenoh::handlers::DefaultHandler> {
+    fn apply_opts(self, opts: rustler::Term) -> rustler::NifResult<Self> {
+        let mut opts_iter: rustler::ListIterator = opts.decode()?;
+
+        opts_iter.try_fold(self, |builder, opt| {
+            let (k, v): (rustler::Atom, rustler::Term) = opt.decode()?;
+            match k {
+                k if k == crate::atoms::history() => match decode_history_config(v)? {
+                    Some(config) => Ok(builder.history(config)),
+                    None => Ok(builder),
+                },
+                k if k == crate::atoms::query_timeout() => {
+                    let timeout = v.decode::<u64>()?;
+                    Ok(builder.query_timeout(Duration::from_millis(timeout)))
+                }
+                k if k == crate::atoms::recovery() => match decode_recovery_config(v)? {
+                    Some(config) => Ok(builder.recovery(config)),
+                    None => Ok(builder),
+                },
+                k if k == crate::atoms::subscriber_detection() => {
+                    if v.decode::<bool>()? {
+                        Ok(builder.subscriber_detection())
+                    } else {
+                        Ok(builder)
+                    }
+                }
+                _ => Ok(builder),
+            }
+        })
+    }
+}
+
+impl Builder for zenoh_ext::AdvancedPublicationBuilder<'_, zenoh::pubsub::PublicationBuilderPut> {
+    fn apply_opts(self, opts: rustler::Term) -> rustler::NifResult<Self> {
+        let mut opts_iter: rustler::ListIterator = opts.decode()?;
+
+        opts_iter.try_fold(self, |builder, opt| {
+            let (k, v): (rustler::Atom, rustler::Term) = opt.decode()?;
+            match k {
+                k if k == crate::atoms::attachment() => {
+                    if let Some(binary) = v.decode::<Option<rustler::Binary>>()? {
+                        Ok(builder.attachment(binary.as_slice()))
+                    } else {
+                        Ok(builder)
+                    }
+                }
+                k if k == crate::atoms::encoding() => {
+                    let encoding = v.decode::<&str>()?;
+                    Ok(builder.encoding(encoding))
+                }
+                k if k == crate::atoms::source_info() => match decode_source_info(v)? {
+                    Some(source_info) => Ok(builder.source_info(source_info)),
+                    None => Ok(builder),
+                },
+                k if k == crate::atoms::timestamp() => {
+                    if let Some(timestamp) =
+                        v.decode::<Option<crate::timestamp::ZenohexTimestamp>>()?
+                    {
+                        Ok(builder.timestamp(zenoh::time::Timestamp::try_from(timestamp)?))
+                    } else {
+                        Ok(builder)
+                    }
+                }
+                _ => Ok(builder),
+            }
+        })
+    }
+}
+
+impl Builder
+    for zenoh_ext::AdvancedPublicationBuilder<'_, zenoh::pubsub::PublicationBuilderDelete>
+{
+    fn apply_opts(self, opts: rustler::Term) -> rustler::NifResult<Self> {
+        let mut opts_iter: rustler::ListIterator = opts.decode()?;
+
+        opts_iter.try_fold(self, |builder, opt| {
+            let (k, v): (rustler::Atom, rustler::Term) = opt.decode()?;
+            match k {
+                k if k == crate::atoms::attachment() => {
+                    if let Some(binary) = v.decode::<Option<rustler::Binary>>()? {
+                        Ok(builder.attachment(binary.as_slice()))
+                    } else {
+                        Ok(builder)
+                    }
+                }
+                k if k == crate::atoms::source_info() => match decode_source_info(v)? {
+                    Some(source_info) => Ok(builder.source_info(source_info)),
+                    None => Ok(builder),
+                },
+                k if k == crate::atoms::timestamp() => {
+                    if let Some(timestamp) =
+                        v.decode::<Option<crate::timestamp::ZenohexTimestamp>>()?
+                    {
+                        Ok(builder.timestamp(zenoh::time::Timestamp::try_from(timestamp)?))
+                    } else {
+                        Ok(builder)
+                    }
+                }
+                _ => Ok(builder),
+            }
+        })
+    }
+}
+
+// Returns the options of an entity declaration as zenoh applies them, for `session_entities`:
+// the given options, and the zenoh defaults of the ones not given.
+// Options the declaration does not support are left out, as the builders ignore them.
+pub fn effective_declare_opts<'a>(
+    kind: crate::session::EntityKind,
+    queries_default_timeout: u64,
+    opts: rustler::Term<'a>,
+) -> rustler::NifResult<Vec<(rustler::Atom, rustler::Term<'a>)>> {
+    let mut effective = default_declare_opts(opts.get_env(), kind, queries_default_timeout);
+
+    // NOTE: zenoh-ext does not expose the default query timeout of advanced subscribers,
+    //       so it is only listed when given.
+    let without_default = match kind {
+        crate::session::EntityKind::AdvancedSubscriber => vec![crate::atoms::query_timeout()],
+        _ => vec![],
+    };
+
+    let opts_iter: rustler::ListIterator = opts.decode()?;
+    for opt in opts_iter {
+        let (k, v): (rustler::Atom, rustler::Term) = opt.decode()?;
+        match effective.iter_mut().find(|(key, _)| *key == k) {
+            Some((_, value)) => *value = v,
+            None if without_default.contains(&k) => effective.push((k, v)),
+            None => {}
+        }
+    }
+
+    Ok(effective)
+}
+
+fn default_declare_opts(
+    env: rustler::Env<'_>,
+    kind: crate::session::EntityKind,
+    queries_default_timeout: u64,
+) -> Vec<(rustler::Atom, rustler::Term<'_>)> {
+    use crate::session::EntityKind;
+    use rustler::Encoder;
+
+    let publisher = || {
+        vec![
+            (
+                crate::atoms::allowed_destination(),
+                Locality::Any.encode(env),
+            ),
+            (
+                crate::atoms::congestion_control(),
+                CongestionControl::from(zenoh::qos::CongestionControl::DEFAULT).encode(env),
+            ),
+            (
+                crate::atoms::encoding(),
+                zenoh::bytes::Encoding::default().to_string().encode(env),
+            ),
+            (crate::atoms::express(), false.encode(env)),
+            (
+                crate::atoms::priority(),
+                Priority::from(zenoh::qos::Priority::DEFAULT).encode(env),
+            ),
+            (
+                crate::atoms::reliability(),
+                Reliability::from(zenoh::qos::Reliability::DEFAULT).encode(env),
+            ),
+        ]
+    };
+    let subscriber = || vec![(crate::atoms::allowed_origin(), Locality::Any.encode(env))];
+
+    match kind {
+        EntityKind::Publisher => publisher(),
+        EntityKind::AdvancedPublisher => [
+            publisher(),
+            vec![
+                (crate::atoms::cache(), false.encode(env)),
+                (crate::atoms::publisher_detection(), false.encode(env)),
+                (crate::atoms::sample_miss_detection(), false.encode(env)),
+            ],
+        ]
+        .concat(),
+        // NOTE: Queries are sent with the request QoS, which blocks on congestion.
+        EntityKind::Querier => vec![
+            (
+                crate::atoms::accept_replies(),
+                ReplyKeyExpr::MatchingQuery.encode(env),
+            ),
+            (
+                crate::atoms::allowed_destination(),
+                Locality::Any.encode(env),
+            ),
+            (
+                crate::atoms::congestion_control(),
+                CongestionControl::Block.encode(env),
+            ),
+            (
+                crate::atoms::consolidation(),
+                QueryConsolidation::Auto.encode(env),
+            ),
+            (crate::atoms::express(), false.encode(env)),
+            (
+                crate::atoms::priority(),
+                Priority::from(zenoh::qos::Priority::DEFAULT).encode(env),
+            ),
+            (
+                crate::atoms::query_timeout(),
+                queries_default_timeout.encode(env),
+            ),
+            (
+                crate::atoms::target(),
+                QueryTarget::BestMatching.encode(env),
+            ),
+        ],
+        EntityKind::Subscriber => subscriber(),
+        EntityKind::AdvancedSubscriber => [
+            subscriber(),
+            vec![
+                (crate::atoms::history(), false.encode(env)),
+                (crate::atoms::recovery(), false.encode(env)),
+                (crate::atoms::subscriber_detection(), false.encode(env)),
+            ],
+        ]
+        .concat(),
+        EntityKind::LivelinessSubscriber => vec![(crate::atoms::history(), false.encode(env))],
+        EntityKind::Queryable => vec![
+            (crate::atoms::allowed_origin(), Locality::Any.encode(env)),
+            (crate::atoms::complete(), false.encode(env)),
+        ],
+    }
+}
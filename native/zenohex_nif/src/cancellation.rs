@@ -0,0 +1,44 @@
+use std::ops::Deref;
+
+use zenoh::Wait;
+
+pub struct CancellationTokenResource(zenoh::cancellation::CancellationToken);
+
+#[rustler::resource_impl]
+impl rustler::Resource for CancellationTokenResource {}
+
+impl Deref for CancellationTokenResource {
+    type Target = zenoh::cancellation::CancellationToken;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[rustler::nif]
+fn cancellation_token_new() -> rustler::ResourceArc<CancellationTokenResource> {
+    rustler::ResourceArc::new(CancellationTokenResource(
+        zenoh::cancellation::CancellationToken::default(),
+    ))
+}
+
+// NOTE: `cancel` blocks until running reply callbacks have finished,
+//       so this runs on a dirty scheduler.
+#[rustler::nif(schedule = "DirtyIo")]
+fn cancellation_token_cancel(
+    cancellation_token_resource: rustler::ResourceArc<CancellationTokenResource>,
+) -> rustler::NifResult<rustler::Atom> {
+    cancellation_token_resource
+        .cancel()
+        .wait()
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    Ok(rustler::types::atom::ok())
+}
+
+#[rustler::nif(name = "cancellation_token_cancelled?")]
+fn cancellation_token_is_cancelled(
+    cancellation_token_resource: rustler::ResourceArc<CancellationTokenResource>,
+) -> bool {
+    cancellation_token_resource.is_cancelled()
+}
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use rustler::Encoder;
+use zenoh::Wait;
+
+use crate::helper::delivery::Delivery;
+
+pub enum ConnectivityEvent {
+    Transport(zenoh::session::TransportEvent),
+    SessionClosed(zenoh::session::ZenohId, SessionCloseReason),
+}
+
+#[derive(Clone, Copy, rustler::NifUnitEnum)]
+pub enum SessionCloseReason {
+    Close,
+    Drop,
+}
+
+#[derive(rustler::NifMap)]
+struct ZenohexSessionClosed {
+    zid: String,
+    reason: SessionCloseReason,
+}
+
+type ListenerState = (
+    zenoh::session::TransportEventsListener<()>,
+    Arc<Delivery<ConnectivityEvent>>,
+);
+
+struct ConnectivityListenerResource(Mutex<Option<ListenerState>>);
+
+#[rustler::resource_impl]
+impl rustler::Resource for ConnectivityListenerResource {}
+
+impl ConnectivityListenerResource {
+    fn new(
+        listener: zenoh::session::TransportEventsListener<()>,
+        delivery: Arc<Delivery<ConnectivityEvent>>,
+    ) -> Self {
+        ConnectivityListenerResource(Mutex::new(Some((listener, delivery))))
+    }
+}
+
+impl Drop for ConnectivityListenerResource {
+    fn drop(&mut self) {
+        let mut state_option = self.0.lock().unwrap();
+        match state_option.take() {
+            Some((listener, _delivery)) => {
+                if let Err(error) = listener.undeclare().wait() {
+                    log::debug!("connectivity listener drop undeclare failed: {}", error);
+                }
+            }
+            None => log::debug!("connectivity listener already undeclared"),
+        }
+    }
+}
+
+#[rustler::nif]
+fn connectivity_declare_listener(
+    session_id_resource: rustler::ResourceArc<crate::session::SessionIdResource>,
+    pid: rustler::LocalPid,
+    opts: rustler::Term,
+) -> rustler::NifResult<(
+    rustler::Atom,
+    rustler::ResourceArc<ConnectivityListenerResource>,
+)> {
+    let session_id = &session_id_resource;
+    let session =
+        crate::session::SessionMap::get_session(&crate::session::SESSION_MAP, session_id)?;
+    let mut session_locked = session.write().unwrap();
+
+    let history = match crate::helper::keyword::get_value(opts, crate::atoms::history())? {
+        Some(term) => term.decode::<bool>()?,
+        None => false,
+    };
+
+    // WHY: Look up links on the delivery thread, not in the zenoh callback,
+    //      so that the transport manager is never queried from within its own event dispatch.
+    //      Links of a closed transport are gone, so the ones seen when it opened are reported.
+    let zenoh_session = (*session_locked).clone();
+    let opened_links: Mutex<HashMap<zenoh::session::ZenohId, Vec<crate::session::ZenohexLink>>> =
+        Mutex::new(HashMap::new());
+
+    let delivery = Arc::new(Delivery::spawn(pid, move |env, event| match event {
+        ConnectivityEvent::Transport(event) => {
+            let transport = event.transport();
+            let mut opened_links = opened_links.lock().unwrap();
+
+            let (kind, links) = match event.kind() {
+                zenoh::sample::SampleKind::Put => {
+                    let links: Vec<crate::session::ZenohexLink> = zenoh_session
+                        .info()
+                        .links()
+                        .transport(transport.clone())
+                        .wait()
+                        .map(crate::session::ZenohexLink::from)
+                        .collect();
+                    opened_links.insert(*transport.zid(), links.clone());
+                    (crate::atoms::opened(), links)
+                }
+                zenoh::sample::SampleKind::Delete => {
+                    let links = opened_links.remove(transport.zid()).unwrap_or_default();
+                    (crate::atoms::closed(), links)
+                }
+            };
+
+            let transport = crate::session::ZenohexTransport::new(transport, links);
+
+            (crate::atoms::zenohex_transport(), kind, transport).encode(env)
+        }
+        ConnectivityEvent::SessionClosed(zid, reason) => {
+            let session_closed = ZenohexSessionClosed {
+                zid: zid.to_string(),
+                reason,
+            };
+
+            (
+                crate::atoms::zenohex_session(),
+                crate::atoms::closed(),
+                session_closed,
+            )
+                .encode(env)
+        }
+    }));
+
+    let callback_delivery = delivery.clone();
+    let listener = session_locked
+        .info()
+        .transport_events_listener()
+        .history(history)
+        .callback(move |event| callback_delivery.push(ConnectivityEvent::Transport(event)))
+        .wait()
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    session_locked.insert_connectivity_delivery(&delivery);
+
+    Ok((
+        rustler::types::atom::ok(),
+        rustler::ResourceArc::new(ConnectivityListenerResource::new(listener, delivery)),
+    ))
+}
+
+#[rustler::nif]
+fn connectivity_undeclare_listener(
+    connectivity_listener_resource: rustler::ResourceArc<ConnectivityListenerResource>,
+) -> rustler::NifResult<rustler::Atom> {
+    let mut state_option = connectivity_listener_resource.0.lock().unwrap();
+
+    match state_option.take() {
+        Some((listener, _delivery)) => {
+            listener
+                .undeclare()
+                .wait()
+                .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+            Ok(rustler::types::atom::ok())
+        }
+        None => Err(rustler::Error::Term(Box::new("already undeclared"))),
+    }
+}
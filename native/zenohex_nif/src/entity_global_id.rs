@@ -0,0 +1,37 @@
+use std::str::FromStr;
+
+#[derive(rustler::NifStruct)]
+#[module = "Zenohex.EntityGlobalId"]
+pub struct ZenohexEntityGlobalId {
+    zid: String,
+    eid: u32,
+}
+
+impl From<&zenoh::session::EntityGlobalId> for ZenohexEntityGlobalId {
+    fn from(entity_global_id: &zenoh::session::EntityGlobalId) -> Self {
+        ZenohexEntityGlobalId {
+            zid: entity_global_id.zid().to_string(),
+            eid: entity_global_id.eid(),
+        }
+    }
+}
+
+impl TryFrom<ZenohexEntityGlobalId> for zenoh::session::EntityGlobalId {
+    type Error = rustler::Error;
+
+    fn try_from(value: ZenohexEntityGlobalId) -> Result<Self, Self::Error> {
+        let zid = zenoh::session::ZenohId::from_str(&value.zid)
+            .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+        Ok(zenoh::session::EntityGlobalId::new(zid, value.eid))
+    }
+}
+
+#[rustler::nif]
+fn entity_global_id_from_entity(
+    entity_global_id_resource: rustler::ResourceArc<crate::session::EntityGlobalIdResource>,
+) -> ZenohexEntityGlobalId {
+    let entity_global_id: &zenoh::session::EntityGlobalId = &entity_global_id_resource;
+
+    entity_global_id.into()
+}
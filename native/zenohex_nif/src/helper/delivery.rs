@@ -0,0 +1,70 @@
+use std::sync::mpsc;
+
+// WHY: Deliver messages from one long-lived thread per entity.
+//      `rustler::OwnedEnv` panics when used from a BEAM managed thread, and zenoh may run
+//      callbacks on the calling NIF thread (e.g. for session-local publications).
+//      Spawning a thread per message avoids the panic, but the spawned threads race,
+//      so messages could reach the BEAM out of order and thread count is unbounded.
+//      See: https://docs.rs/rustler/latest/rustler/env/struct.OwnedEnv.html#panics
+pub struct Delivery<T>(mpsc::Sender<T>);
+
+impl<T: Send + 'static> Delivery<T> {
+    pub fn spawn<F>(pid: rustler::LocalPid, encode: F) -> Delivery<T>
+    where
+        F: for<'a> Fn(rustler::Env<'a>, T) -> rustler::Term<'a> + Send + 'static,
+    {
+        Delivery::start(pid, encode, |_env| None, || false)
+    }
+
+    // Same as `spawn`, but also sends the term built by `done`, if any, once zenoh drops
+    // the callback, so the receiver knows that no more messages will follow.
+    // Queued messages are dropped instead of sent once `skip` returns `true`.
+    pub fn spawn_with_done<F, D, S>(
+        pid: rustler::LocalPid,
+        encode: F,
+        done: D,
+        skip: S,
+    ) -> Delivery<T>
+    where
+        F: for<'a> Fn(rustler::Env<'a>, T) -> rustler::Term<'a> + Send + 'static,
+        D: for<'a> FnOnce(rustler::Env<'a>) -> Option<rustler::Term<'a>> + Send + 'static,
+        S: Fn() -> bool + Send + 'static,
+    {
+        Delivery::start(pid, encode, done, skip)
+    }
+
+    fn start<F, D, S>(pid: rustler::LocalPid, encode: F, done: D, skip: S) -> Delivery<T>
+    where
+        F: for<'a> Fn(rustler::Env<'a>, T) -> rustler::Term<'a> + Send + 'static,
+        D: for<'a> FnOnce(rustler::Env<'a>) -> Option<rustler::Term<'a>> + Send + 'static,
+        S: Fn() -> bool + Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel::<T>();
+
+        std::thread::spawn(move || {
+            let mut owned_env = rustler::OwnedEnv::new();
+
+            // NOTE: `recv` returns an error once every `Delivery` has been dropped,
+            //       which happens when zenoh drops the callback owning it.
+            while let Ok(message) = rx.recv() {
+                if skip() {
+                    continue;
+                }
+                // The receiver process may already be dead; keep draining the queue.
+                let _ = owned_env.send_and_clear(&pid, |env| encode(env, message));
+            }
+
+            let _ = owned_env.run(|env| match done(env) {
+                Some(term) => env.send(&pid, term),
+                None => Ok(()),
+            });
+        });
+
+        Delivery(tx)
+    }
+
+    pub fn push(&self, message: T) {
+        // The worker thread only exits after this sender is dropped, so this never fails.
+        let _ = self.0.send(message);
+    }
+}
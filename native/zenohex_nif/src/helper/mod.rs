@@ -1,3 +1,6 @@
+pub mod delivery;
 pub mod exception;
 pub mod keyword;
 pub mod logger;
+pub mod sample_store;
+pub mod term;
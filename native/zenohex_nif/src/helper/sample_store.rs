@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::RwLock;
+use std::time::Duration;
+use std::time::Instant;
+
+// NOTE: Same default as the garbage collection lifespan of zenoh-plugin-storage-manager.
+const DEFAULT_TOMBSTONE_LIFESPAN: Duration = Duration::from_secs(86_400);
+
+#[derive(Default)]
+struct Samples {
+    samples: HashMap<zenoh::key_expr::OwnedKeyExpr, zenoh::sample::Sample>,
+    // Tombstones in arrival order, with the timestamp of the delete which made them.
+    tombstones: VecDeque<(
+        Instant,
+        zenoh::key_expr::OwnedKeyExpr,
+        zenoh::time::Timestamp,
+    )>,
+}
+
+// The latest sample per key, kept in Rust so that it can be read without message passing.
+//
+// NOTE: A sample older than the stored one, by timestamp, is ignored.
+//       A timestamped delete is kept as a tombstone, so that an older put received late
+//       is ignored too. Samples without timestamp are applied in arrival order.
+//       Tombstones are collected once they are older than `tombstone_lifespan`,
+//       after which such a late put is stored again.
+pub struct SampleStore {
+    samples: RwLock<Samples>,
+    tombstone_lifespan: Duration,
+}
+
+impl Default for SampleStore {
+    fn default() -> Self {
+        SampleStore::new(DEFAULT_TOMBSTONE_LIFESPAN)
+    }
+}
+
+impl SampleStore {
+    pub fn new(tombstone_lifespan: Duration) -> Self {
+        SampleStore {
+            samples: RwLock::new(Samples::default()),
+            tombstone_lifespan,
+        }
+    }
+
+    // Decodes the `tombstone_lifespan` option, in milliseconds.
+    pub fn from_opts(opts: rustler::Term) -> rustler::NifResult<Self> {
+        match crate::helper::keyword::get_value(opts, crate::atoms::tombstone_lifespan())? {
+            Some(term) => Ok(SampleStore::new(Duration::from_millis(term.decode()?))),
+            None => Ok(SampleStore::default()),
+        }
+    }
+
+    pub fn insert(&self, sample: zenoh::sample::Sample) {
+        let key_expr = zenoh::key_expr::OwnedKeyExpr::from(sample.key_expr().clone());
+        let mut locked = self.samples.write().unwrap();
+        let Samples {
+            samples,
+            tombstones,
+        } = &mut *locked;
+
+        // WHY: Collected on insert, since only inserts make tombstones.
+        //      A tombstone overwritten since is no longer the stored sample, and is skipped.
+        while let Some((instant, _, _)) = tombstones.front() {
+            if instant.elapsed() < self.tombstone_lifespan {
+                break;
+            }
+
+            let (_, tombstone_key_expr, tombstone_timestamp) = tombstones.pop_front().unwrap();
+
+            if samples.get(&tombstone_key_expr).is_some_and(|stored| {
+                stored.kind() == zenoh::sample::SampleKind::Delete
+                    && stored.timestamp() == Some(&tombstone_timestamp)
+            }) {
+                samples.remove(&tombstone_key_expr);
+            }
+        }
+
+        if let Some(stored) = samples.get(&key_expr) {
+            if let (Some(timestamp), Some(stored_timestamp)) =
+                (sample.timestamp(), stored.timestamp())
+            {
+                if timestamp <= stored_timestamp {
+                    return;
+                }
+            }
+        }
+
+        match (sample.kind(), sample.timestamp()) {
+            (zenoh::sample::SampleKind::Delete, None) => {
+                samples.remove(&key_expr);
+            }
+            (zenoh::sample::SampleKind::Delete, Some(timestamp)) => {
+                tombstones.push_back((Instant::now(), key_expr.clone(), *timestamp));
+                samples.insert(key_expr, sample);
+            }
+            _ => {
+                samples.insert(key_expr, sample);
+            }
+        }
+    }
+
+    pub fn get(&self, key_expr: &zenoh::key_expr::keyexpr) -> Option<zenoh::sample::Sample> {
+        let locked = self.samples.read().unwrap();
+
+        locked
+            .samples
+            .get(key_expr)
+            .filter(|sample| sample.kind() == zenoh::sample::SampleKind::Put)
+            .cloned()
+    }
+
+    // Returns the put samples whose key intersects `key_expr`.
+    pub fn intersecting(&self, key_expr: &zenoh::key_expr::keyexpr) -> Vec<zenoh::sample::Sample> {
+        let locked = self.samples.read().unwrap();
+
+        locked
+            .samples
+            .iter()
+            .filter(|(stored_key_expr, sample)| {
+                sample.kind() == zenoh::sample::SampleKind::Put
+                    && key_expr.intersects(stored_key_expr)
+            })
+            .map(|(_, sample)| sample.clone())
+            .collect()
+    }
+
+    pub fn keys(&self) -> Vec<String> {
+        let locked = self.samples.read().unwrap();
+
+        locked
+            .samples
+            .iter()
+            .filter(|(_, sample)| sample.kind() == zenoh::sample::SampleKind::Put)
+            .map(|(key_expr, _)| key_expr.to_string())
+            .collect()
+    }
+
+    // Removes the samples whose key intersects `key_expr`, tombstones included,
+    // and returns how many put samples were removed.
+    pub fn purge(&self, key_expr: &zenoh::key_expr::keyexpr) -> usize {
+        let mut locked = self.samples.write().unwrap();
+        let mut purged = 0;
+
+        locked
+            .tombstones
+            .retain(|(_, tombstone_key_expr, _)| !key_expr.intersects(tombstone_key_expr));
+        locked.samples.retain(|stored_key_expr, sample| {
+            let retain = !key_expr.intersects(stored_key_expr);
+            if !retain && sample.kind() == zenoh::sample::SampleKind::Put {
+                purged += 1;
+            }
+            retain
+        });
+
+        purged
+    }
+}
@@ -0,0 +1,18 @@
+// WHY: Keep a term alive outside of the NIF call in external term format.
+//      `rustler::SavedTerm` is bound to one `rustler::OwnedEnv` and becomes invalid
+//      when that env is cleared, which `send_and_clear` does after every message.
+#[derive(Clone)]
+pub struct EncodedTerm(Vec<u8>);
+
+impl EncodedTerm {
+    pub fn new(term: rustler::Term) -> EncodedTerm {
+        EncodedTerm(term.to_binary().as_slice().to_vec())
+    }
+
+    pub fn decode<'a>(&self, env: rustler::Env<'a>) -> rustler::Term<'a> {
+        let (term, _size) = env
+            .binary_to_term(&self.0)
+            .expect("term was encoded by EncodedTerm::new");
+        term
+    }
+}
@@ -1,3 +1,96 @@
+use std::sync::Mutex;
+
+use zenoh::Wait;
+
+// A key expression declared with `session_declare_keyexpr`.
+//
+// NOTE: zenoh undeclares the key expression when its last clone is dropped,
+//       so entities declared with it keep it declared until they are undeclared too.
+pub struct KeyExprResource {
+    session_id_resource: rustler::ResourceArc<crate::session::SessionIdResource>,
+    declared: Mutex<Option<zenoh::key_expr::KeyExpr<'static>>>,
+    key_expr: zenoh::key_expr::OwnedKeyExpr,
+}
+
+#[rustler::resource_impl]
+impl rustler::Resource for KeyExprResource {}
+
+impl KeyExprResource {
+    pub fn new(
+        session_id_resource: rustler::ResourceArc<crate::session::SessionIdResource>,
+        declared: zenoh::key_expr::KeyExpr<'static>,
+    ) -> KeyExprResource {
+        KeyExprResource {
+            session_id_resource,
+            key_expr: declared.clone().into(),
+            declared: Mutex::new(Some(declared)),
+        }
+    }
+
+    // Returns the declared key expression, or the plain one once undeclared.
+    fn key_expr(&self) -> zenoh::key_expr::KeyExpr<'static> {
+        match self.declared.lock().unwrap().as_ref() {
+            Some(declared) => declared.clone(),
+            None => self.key_expr.clone().into(),
+        }
+    }
+}
+
+// A key expression argument, which is either a string or a `KeyExprResource`.
+// Accepted by the NIFs taking a key expression, so that declared ones can be used anywhere.
+#[derive(Clone)]
+pub struct KeyExprArg(zenoh::key_expr::KeyExpr<'static>);
+
+impl<'a> rustler::Decoder<'a> for KeyExprArg {
+    fn decode(term: rustler::Term<'a>) -> rustler::NifResult<Self> {
+        if let Ok(key_expr_resource) = term.decode::<rustler::ResourceArc<KeyExprResource>>() {
+            return Ok(KeyExprArg(key_expr_resource.key_expr()));
+        }
+
+        zenoh::key_expr::KeyExpr::try_from(term.decode::<String>()?)
+            .map(KeyExprArg)
+            .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))
+    }
+}
+
+impl std::ops::Deref for KeyExprArg {
+    type Target = zenoh::key_expr::KeyExpr<'static>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<KeyExprArg> for zenoh::key_expr::KeyExpr<'static> {
+    fn from(value: KeyExprArg) -> Self {
+        value.0
+    }
+}
+
+#[rustler::nif]
+fn keyexpr_undeclare(
+    key_expr_resource: rustler::ResourceArc<KeyExprResource>,
+) -> rustler::NifResult<rustler::Atom> {
+    let mut declared_option = key_expr_resource.declared.lock().unwrap();
+
+    match declared_option.take() {
+        Some(declared) => {
+            let session_id = &key_expr_resource.session_id_resource;
+            let session =
+                crate::session::SessionMap::get_session(&crate::session::SESSION_MAP, session_id)?;
+            let session_locked = session.read().unwrap();
+
+            session_locked
+                .undeclare(declared)
+                .wait()
+                .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+            Ok(rustler::types::atom::ok())
+        }
+        None => Err(rustler::Error::Term(Box::new("already undeclared"))),
+    }
+}
+
 #[rustler::nif]
 fn keyexpr_autocanonize(key_expr: String) -> rustler::NifResult<(rustler::Atom, String)> {
     let mut key_expr = key_expr;
@@ -43,7 +136,227 @@ fn keyexpr_join(key_expr1: &str, key_expr2: &str) -> rustler::NifResult<(rustler
         .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))
 }
 
-fn new(key_expr: &str) -> rustler::NifResult<&zenoh::key_expr::keyexpr> {
+#[rustler::nif]
+fn keyexpr_canonize(key_expr: String) -> rustler::NifResult<(rustler::Atom, String)> {
+    let mut canonized = key_expr.clone();
+    zenoh::key_expr::keyexpr::autocanonize(&mut canonized)
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    if canonized == key_expr {
+        Ok((rustler::types::atom::ok(), key_expr))
+    } else {
+        Err(rustler::Error::Term(Box::new((
+            crate::atoms::not_canon(),
+            canonized,
+        ))))
+    }
+}
+
+#[derive(rustler::NifUnitEnum)]
+enum Relation {
+    Disjoint,
+    Intersects,
+    Includes,
+    Equals,
+}
+
+impl From<zenoh::key_expr::SetIntersectionLevel> for Relation {
+    fn from(value: zenoh::key_expr::SetIntersectionLevel) -> Self {
+        match value {
+            zenoh::key_expr::SetIntersectionLevel::Disjoint => Relation::Disjoint,
+            zenoh::key_expr::SetIntersectionLevel::Intersects => Relation::Intersects,
+            zenoh::key_expr::SetIntersectionLevel::Includes => Relation::Includes,
+            zenoh::key_expr::SetIntersectionLevel::Equals => Relation::Equals,
+        }
+    }
+}
+
+#[rustler::nif]
+fn keyexpr_relation_to(key_expr1: &str, key_expr2: &str) -> rustler::NifResult<Relation> {
+    let key_expr1 = new(key_expr1)?;
+    let key_expr2 = new(key_expr2)?;
+
+    Ok(key_expr1.relation_to(key_expr2).into())
+}
+
+#[rustler::nif]
+fn keyexpr_concat(key_expr: &str, suffix: &str) -> rustler::NifResult<(rustler::Atom, String)> {
+    zenoh::key_expr::KeyExpr::try_from(key_expr)
+        .and_then(|key_expr| key_expr.concat(suffix))
+        .map(|key_expr| (rustler::types::atom::ok(), key_expr.to_string()))
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))
+}
+
+#[rustler::nif]
+fn keyexpr_chunks(key_expr: &str) -> rustler::NifResult<Vec<String>> {
+    let key_expr = new(key_expr)?;
+
+    Ok(key_expr.chunks().map(|chunk| chunk.to_string()).collect())
+}
+
+#[rustler::nif(name = "keyexpr_wild?")]
+fn keyexpr_wild(key_expr: &str) -> rustler::NifResult<bool> {
+    let key_expr = new(key_expr)?;
+
+    Ok(key_expr.is_wild())
+}
+
+#[rustler::nif]
+fn keyexpr_strip_prefix(key_expr: &str, prefix: &str) -> rustler::NifResult<Vec<String>> {
+    let key_expr = new(key_expr)?;
+    let prefix = new(prefix)?;
+
+    Ok(key_expr
+        .strip_prefix(prefix)
+        .into_iter()
+        .map(|suffix| suffix.to_string())
+        .collect())
+}
+
+#[rustler::nif]
+fn keyexpr_strip_nonwild_prefix(
+    key_expr: &str,
+    prefix: &str,
+) -> rustler::NifResult<Option<String>> {
+    let key_expr = new(key_expr)?;
+    let prefix = zenoh::key_expr::nonwild_keyexpr::new(prefix).map_err(|error| {
+        rustler::Error::RaiseTerm(Box::new(crate::helper::exception::ArgumentError {
+            message: error.to_string(),
+        }))
+    })?;
+
+    Ok(key_expr
+        .strip_nonwild_prefix(prefix)
+        .map(|suffix| suffix.to_string()))
+}
+
+#[derive(Clone, Copy, rustler::NifUnitEnum)]
+enum InvalidReason {
+    EmptyChunk,
+    StarInChunk,
+    SingleStarAfterDoubleStar,
+    DoubleStarAfterDoubleStar,
+    LoneDollarStar,
+    DollarAfterDollar,
+    UnboundDollar,
+    ForbiddenCharacter,
+    // Rejected by zenoh for a reason `find_invalid` does not know about.
+    Other,
+}
+
+impl InvalidReason {
+    fn message(self) -> &'static str {
+        match self {
+            InvalidReason::EmptyChunk => {
+                "empty chunks are forbidden, as well as leading and trailing slashes"
+            }
+            InvalidReason::StarInChunk => "`*` may only be preceded by `/` or `$`",
+            InvalidReason::SingleStarAfterDoubleStar => {
+                "`**/*` must be replaced by `*/**` to reach canon-form"
+            }
+            InvalidReason::DoubleStarAfterDoubleStar => {
+                "`**/**` must be replaced by `**` to reach canon-form"
+            }
+            InvalidReason::LoneDollarStar => "`$*` alone in a chunk must be replaced by `*`",
+            InvalidReason::DollarAfterDollar => "`$` is not allowed after `$*`",
+            InvalidReason::UnboundDollar => "`$` is only allowed in `$*`",
+            InvalidReason::ForbiddenCharacter => "`#` and `?` are forbidden characters",
+            InvalidReason::Other => "rejected by zenoh",
+        }
+    }
+}
+
+#[derive(rustler::NifStruct)]
+#[module = "Zenohex.KeyExpr.ValidationError"]
+struct ValidationError {
+    reason: InvalidReason,
+    position: Option<usize>,
+    message: String,
+}
+
+// Finds the first rule of the key expression syntax broken by `key_expr`,
+// and the byte offset of the offending chunk or character.
+//
+// WHY: zenoh only reports invalid key expressions with a message, without position.
+//      Validity itself is still decided by zenoh, see `keyexpr_validate`.
+fn find_invalid(key_expr: &str) -> Option<(InvalidReason, usize)> {
+    let mut offset = 0;
+    let mut previous_chunk = None;
+
+    for chunk in key_expr.split('/') {
+        match chunk {
+            "" => return Some((InvalidReason::EmptyChunk, offset)),
+            "**" if previous_chunk == Some("**") => {
+                return Some((InvalidReason::DoubleStarAfterDoubleStar, offset))
+            }
+            "*" if previous_chunk == Some("**") => {
+                return Some((InvalidReason::SingleStarAfterDoubleStar, offset))
+            }
+            "*" | "**" => {}
+            "$*" => return Some((InvalidReason::LoneDollarStar, offset)),
+            _ => {
+                let bytes = chunk.as_bytes();
+                let mut i = 0;
+                while i < bytes.len() {
+                    match (bytes[i], bytes.get(i + 1), bytes.get(i + 2)) {
+                        (b'#' | b'?', _, _) => {
+                            return Some((InvalidReason::ForbiddenCharacter, offset + i))
+                        }
+                        (b'*', _, _) => return Some((InvalidReason::StarInChunk, offset + i)),
+                        (b'$', Some(b'*'), Some(b'$')) => {
+                            return Some((InvalidReason::DollarAfterDollar, offset + i + 2))
+                        }
+                        (b'$', Some(b'*'), _) => i += 2,
+                        (b'$', _, _) => return Some((InvalidReason::UnboundDollar, offset + i)),
+                        _ => i += 1,
+                    }
+                }
+            }
+        }
+
+        previous_chunk = Some(chunk);
+        offset += chunk.len() + 1;
+    }
+
+    None
+}
+
+// NOTE: If `find_invalid` finds no broken rule for a key expression zenoh rejects,
+//       the error still is a `ValidationError`, with the message of zenoh and no position.
+#[rustler::nif]
+fn keyexpr_validate(key_expr: &str) -> rustler::NifResult<rustler::Atom> {
+    let error = match zenoh::key_expr::keyexpr::new(key_expr) {
+        Ok(_) => return Ok(rustler::types::atom::ok()),
+        Err(error) => error,
+    };
+
+    let validation_error = match find_invalid(key_expr) {
+        Some((reason, position)) => ValidationError {
+            reason,
+            position: Some(position),
+            message: format!(
+                "invalid key expression `{}` at position {}: {}",
+                key_expr,
+                position,
+                reason.message()
+            ),
+        },
+        None => ValidationError {
+            reason: InvalidReason::Other,
+            position: None,
+            message: format!(
+                "invalid key expression `{}`: {}: {}",
+                key_expr,
+                InvalidReason::Other.message(),
+                error
+            ),
+        },
+    };
+
+    Err(rustler::Error::Term(Box::new(validation_error)))
+}
+
+pub fn new(key_expr: &str) -> rustler::NifResult<&zenoh::key_expr::keyexpr> {
     match zenoh::key_expr::keyexpr::new(key_expr) {
         Ok(key_expr) => Ok(key_expr),
         Err(error) => Err(rustler::Error::RaiseTerm(Box::new(
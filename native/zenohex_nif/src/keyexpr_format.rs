@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use zenoh_keyexpr::format::{KeFormat, OwnedKeFormat};
+
+pub struct KeyExprFormatResource(OwnedKeFormat);
+
+#[rustler::resource_impl]
+impl rustler::Resource for KeyExprFormatResource {}
+
+#[rustler::nif]
+fn keyexpr_format_new(
+    format: String,
+) -> rustler::NifResult<(rustler::Atom, rustler::ResourceArc<KeyExprFormatResource>)> {
+    let format = OwnedKeFormat::try_from(format)
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    Ok((
+        rustler::types::atom::ok(),
+        rustler::ResourceArc::new(KeyExprFormatResource(format)),
+    ))
+}
+
+#[rustler::nif]
+fn keyexpr_format_build(
+    format_resource: rustler::ResourceArc<KeyExprFormatResource>,
+    values: HashMap<String, String>,
+) -> rustler::NifResult<(rustler::Atom, String)> {
+    let format: &KeFormat = &format_resource.0;
+    let mut formatter = format.formatter();
+
+    for (id, value) in values {
+        // WHY: `FormatSetError` alone, e.g. "PatternNotMatched", does not tell which chunk failed.
+        formatter.set(&id, &value).map_err(|error| {
+            rustler::Error::Term(crate::zenoh_error!(format!("{id} = {value:?}: {error}")))
+        })?;
+    }
+
+    formatter
+        .build()
+        .map(|key_expr| (rustler::types::atom::ok(), key_expr.to_string()))
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))
+}
+
+#[rustler::nif]
+fn keyexpr_format_parse(
+    format_resource: rustler::ResourceArc<KeyExprFormatResource>,
+    key_expr: &str,
+) -> rustler::NifResult<(rustler::Atom, HashMap<String, String>)> {
+    let format: &KeFormat = &format_resource.0;
+    let key_expr = zenoh_keyexpr::keyexpr::new(key_expr)
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    let parsed = format
+        .parse(key_expr)
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    // NOTE: A chunk whose pattern is `**` may match nothing, its value is then "".
+    let values = parsed
+        .iter()
+        .map(|(id, value)| {
+            let value = value.map_or("", |value| value.as_str());
+            (id.to_string(), value.to_string())
+        })
+        .collect();
+
+    Ok((rustler::types::atom::ok(), values))
+}
@@ -0,0 +1,93 @@
+use std::sync::RwLock;
+
+use zenoh_keyexpr::keyexpr_tree::{IKeyExprTree, IKeyExprTreeMut, IKeyExprTreeNode, KeBoxTree};
+
+use crate::helper::term::EncodedTerm;
+
+pub struct KeyExprTreeResource(RwLock<KeBoxTree<EncodedTerm>>);
+
+#[rustler::resource_impl]
+impl rustler::Resource for KeyExprTreeResource {}
+
+// NOTE: Intermediate nodes of the tree have no weight, only the inserted key expressions do.
+fn weights<'a, 'b, Node: IKeyExprTreeNode<EncodedTerm> + 'b>(
+    env: rustler::Env<'a>,
+    nodes: impl Iterator<Item = &'b Node>,
+) -> Vec<rustler::Term<'a>> {
+    nodes
+        .filter_map(|node| node.weight())
+        .map(|weight| weight.decode(env))
+        .collect()
+}
+
+#[rustler::nif]
+fn keyexpr_tree_new() -> rustler::ResourceArc<KeyExprTreeResource> {
+    rustler::ResourceArc::new(KeyExprTreeResource(RwLock::new(KeBoxTree::new())))
+}
+
+#[rustler::nif]
+fn keyexpr_tree_insert(
+    tree_resource: rustler::ResourceArc<KeyExprTreeResource>,
+    key_expr: &str,
+    term: rustler::Term,
+) -> rustler::NifResult<rustler::Atom> {
+    let key_expr = crate::keyexpr::new(key_expr)?;
+    let mut tree = tree_resource.0.write().unwrap();
+
+    tree.insert(key_expr, EncodedTerm::new(term));
+
+    Ok(rustler::types::atom::ok())
+}
+
+#[rustler::nif]
+fn keyexpr_tree_remove(
+    tree_resource: rustler::ResourceArc<KeyExprTreeResource>,
+    key_expr: &str,
+) -> rustler::NifResult<rustler::Atom> {
+    let key_expr = crate::keyexpr::new(key_expr)?;
+    let mut tree = tree_resource.0.write().unwrap();
+
+    let removed = tree.remove(key_expr);
+    tree.prune();
+
+    match removed {
+        Some(_) => Ok(rustler::types::atom::ok()),
+        None => Err(rustler::Error::Term(Box::new(crate::atoms::not_found()))),
+    }
+}
+
+#[rustler::nif]
+fn keyexpr_tree_intersecting<'a>(
+    env: rustler::Env<'a>,
+    tree_resource: rustler::ResourceArc<KeyExprTreeResource>,
+    key_expr: &str,
+) -> rustler::NifResult<Vec<rustler::Term<'a>>> {
+    let key_expr = crate::keyexpr::new(key_expr)?;
+    let tree = tree_resource.0.read().unwrap();
+
+    Ok(weights(env, tree.intersecting_nodes(key_expr)))
+}
+
+#[rustler::nif]
+fn keyexpr_tree_including<'a>(
+    env: rustler::Env<'a>,
+    tree_resource: rustler::ResourceArc<KeyExprTreeResource>,
+    key_expr: &str,
+) -> rustler::NifResult<Vec<rustler::Term<'a>>> {
+    let key_expr = crate::keyexpr::new(key_expr)?;
+    let tree = tree_resource.0.read().unwrap();
+
+    Ok(weights(env, tree.nodes_including(key_expr)))
+}
+
+#[rustler::nif]
+fn keyexpr_tree_included<'a>(
+    env: rustler::Env<'a>,
+    tree_resource: rustler::ResourceArc<KeyExprTreeResource>,
+    key_expr: &str,
+) -> rustler::NifResult<Vec<rustler::Term<'a>>> {
+    let key_expr = crate::keyexpr::new(key_expr)?;
+    let tree = tree_resource.0.read().unwrap();
+
+    Ok(weights(env, tree.included_nodes(key_expr)))
+}
@@ -10,42 +10,90 @@ macro_rules! zenoh_error {
     };
 }
 
+mod admin;
 mod builder;
+mod cancellation;
 mod config;
+mod connectivity;
+mod entity_global_id;
 mod helper;
 mod keyexpr;
+mod keyexpr_format;
+mod keyexpr_tree;
 mod liveliness;
+mod lvc;
 mod matching;
+mod plugins;
 mod publisher;
 mod querier;
 mod query;
 mod queryable;
+mod receiver;
 mod sample;
 mod scouting;
+mod selector;
+mod serialization;
 mod session;
+mod shm;
+mod storage;
 mod subscriber;
+mod timestamp;
+mod zenoh_id;
 
 mod atoms {
     rustler::atoms! {
         accept_replies,
         attachment,
+        cache,
+        detect_late_publishers,
+        gt,
         history,
         allowed_destination,
         allowed_origin,
+        cancellation_token,
+        closed,
         complete,
+        config,
         congestion_control,
         consolidation,
+        empty,
         encoding,
+        eq,
         express,
         is_final = "final?",
+        key_expr,
+        kind,
+        list,
+        lt,
+        map,
+        max_age,
+        max_samples,
+        name,
+        not_canon,
+        not_found,
+        opened,
         parameters,
+        paths,
         payload,
         priority,
+        publisher_detection,
         query_timeout,
+        recovery,
+        reliability,
+        required,
+        sample_miss_detection,
+        source_info,
+        subscriber_detection,
         target,
         timeout,
         unsupported_entity,
         timestamp,
+        tombstone_lifespan,
+        tuple,
+        zenohex_get_done,
+        zenohex_get_reply,
+        zenohex_session,
+        zenohex_transport,
         zenohex_nif = "Elixir.Zenohex.Nif",
     }
 }
@@ -41,7 +41,7 @@ impl LivelinessTokenResource {
 fn liveliness_get<'a>(
     env: rustler::Env<'a>,
     session_id_resource: rustler::ResourceArc<crate::session::SessionIdResource>,
-    key_expr: &str,
+    key_expr: crate::keyexpr::KeyExprArg,
     timeout: u64,
     opts: rustler::Term,
 ) -> rustler::NifResult<(rustler::Atom, Vec<rustler::Term<'a>>)> {
@@ -87,23 +87,45 @@ fn liveliness_get<'a>(
             }
         };
 
-        let term = match reply.result() {
-            Ok(sample) => crate::sample::ZenohexSample::from(env, sample.clone()).encode(env),
-            Err(reply_error) => {
-                crate::query::ZenohexQueryReplyError::from(env, reply_error.clone()).encode(env)
-            }
-        };
-
-        replies.push(term);
+        replies.push(crate::query::encode_reply(env, reply));
     }
 
     Ok((rustler::types::atom::ok(), replies))
 }
 
+#[rustler::nif]
+fn liveliness_get_async(
+    session_id_resource: rustler::ResourceArc<crate::session::SessionIdResource>,
+    key_expr: crate::keyexpr::KeyExprArg,
+    // WHY: Pass `pid` instead of using `env.pid()`
+    //      so the user can specify any receiver process
+    pid: rustler::LocalPid,
+    reference: rustler::Term,
+    opts: rustler::Term,
+) -> rustler::NifResult<rustler::Atom> {
+    let session_id = &session_id_resource;
+    let session =
+        crate::session::SessionMap::get_session(&crate::session::SESSION_MAP, session_id)?;
+    let session_locked = session.read().unwrap();
+    let (delivery, get_started) = crate::query::get_async_delivery(pid, reference, opts)?;
+
+    session_locked
+        .liveliness()
+        .get(key_expr)
+        .apply_opts(opts)?
+        .callback(move |reply| delivery.push(reply))
+        .wait()
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    get_started.started();
+
+    Ok(rustler::types::atom::ok())
+}
+
 #[rustler::nif]
 fn liveliness_declare_subscriber(
     session_id_resource: rustler::ResourceArc<crate::session::SessionIdResource>,
-    key_expr: String,
+    key_expr: crate::keyexpr::KeyExprArg,
     // WHY: Pass `pid` instead of using `env.pid()`
     //      so the user can specify any receiver process
     pid: rustler::LocalPid,
@@ -116,21 +138,23 @@ fn liveliness_declare_subscriber(
     let session =
         crate::session::SessionMap::get_session(&crate::session::SESSION_MAP, session_id)?;
     let mut session_locked = session.write().unwrap();
+    let metadata = crate::session::EntityMetadata::new(
+        &session_locked,
+        crate::session::EntityKind::LivelinessSubscriber,
+        key_expr.as_str(),
+        Some(pid),
+        opts,
+    )?;
 
     let liveliness_subscriber_buidler = session_locked.liveliness().declare_subscriber(key_expr);
 
+    let delivery = crate::helper::delivery::Delivery::spawn(pid, |env, sample| {
+        crate::sample::ZenohexSample::from(env, sample).encode(env)
+    });
+
     let subscriber = liveliness_subscriber_buidler
         .apply_opts(opts)?
-        .callback(move |sample| {
-            // WHY: Spawn a thread inside this callback.
-            //      If we don't spawn a thread, a panic will occur.
-            //      See: https://docs.rs/rustler/latest/rustler/env/struct.OwnedEnv.html#panics
-            std::thread::spawn(move || {
-                let _ = rustler::OwnedEnv::new().run(|env: rustler::Env| {
-                    env.send(&pid, crate::sample::ZenohexSample::from(env, sample))
-                });
-            });
-        })
+        .callback(move |sample| delivery.push(sample))
         .wait()
         .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
 
@@ -138,6 +162,7 @@ fn liveliness_declare_subscriber(
     session_locked.insert_entity(
         subscriber_id,
         crate::session::Entity::Subscriber(subscriber, session_id_resource),
+        metadata,
     )?;
 
     Ok((
@@ -149,7 +174,7 @@ fn liveliness_declare_subscriber(
 #[rustler::nif]
 fn liveliness_declare_token(
     session_id_resource: rustler::ResourceArc<crate::session::SessionIdResource>,
-    key_expr: &str,
+    key_expr: crate::keyexpr::KeyExprArg,
 ) -> rustler::NifResult<(rustler::Atom, rustler::ResourceArc<LivelinessTokenResource>)> {
     let session_id = &session_id_resource;
     let session =
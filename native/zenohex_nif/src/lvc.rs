@@ -0,0 +1,118 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use zenoh::Wait;
+
+use crate::builder::Builder;
+use crate::helper::sample_store::SampleStore;
+
+struct LvcResource {
+    subscriber: Mutex<Option<zenoh::pubsub::Subscriber<()>>>,
+    store: Arc<SampleStore>,
+}
+
+#[rustler::resource_impl]
+impl rustler::Resource for LvcResource {}
+
+impl Drop for LvcResource {
+    fn drop(&mut self) {
+        let mut subscriber_option = self.subscriber.lock().unwrap();
+        match subscriber_option.take() {
+            Some(subscriber) => {
+                if let Err(error) = subscriber.undeclare().wait() {
+                    log::debug!("lvc drop undeclare failed: {}", error);
+                }
+            }
+            None => log::debug!("lvc already undeclared"),
+        }
+    }
+}
+
+#[rustler::nif]
+fn lvc_declare(
+    session_id_resource: rustler::ResourceArc<crate::session::SessionIdResource>,
+    key_expr: crate::keyexpr::KeyExprArg,
+    opts: rustler::Term,
+) -> rustler::NifResult<(rustler::Atom, rustler::ResourceArc<LvcResource>)> {
+    let session_id = &session_id_resource;
+    let session =
+        crate::session::SessionMap::get_session(&crate::session::SESSION_MAP, session_id)?;
+    let session_locked = session.read().unwrap();
+
+    let store = Arc::new(SampleStore::from_opts(opts)?);
+
+    // WHY: The callback only updates the store, so samples never reach a BEAM mailbox.
+    //      They are read on demand by the NIFs below.
+    let subscriber_store = store.clone();
+    let subscriber = session_locked
+        .declare_subscriber(key_expr)
+        .apply_opts(opts)?
+        .callback(move |sample| subscriber_store.insert(sample))
+        .wait()
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    Ok((
+        rustler::types::atom::ok(),
+        rustler::ResourceArc::new(LvcResource {
+            subscriber: Mutex::new(Some(subscriber)),
+            store,
+        }),
+    ))
+}
+
+#[rustler::nif]
+fn lvc_undeclare(
+    lvc_resource: rustler::ResourceArc<LvcResource>,
+) -> rustler::NifResult<rustler::Atom> {
+    let mut subscriber_option = lvc_resource.subscriber.lock().unwrap();
+
+    match subscriber_option.take() {
+        Some(subscriber) => {
+            subscriber
+                .undeclare()
+                .wait()
+                .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+            Ok(rustler::types::atom::ok())
+        }
+        None => Err(rustler::Error::Term(Box::new("already undeclared"))),
+    }
+}
+
+#[rustler::nif]
+fn lvc_get<'a>(
+    env: rustler::Env<'a>,
+    lvc_resource: rustler::ResourceArc<LvcResource>,
+    key: &str,
+) -> rustler::NifResult<(rustler::Atom, crate::sample::ZenohexSample<'a>)> {
+    match lvc_resource.store.get(crate::keyexpr::new(key)?) {
+        Some(sample) => Ok((
+            rustler::types::atom::ok(),
+            crate::sample::ZenohexSample::from(env, sample),
+        )),
+        None => Err(rustler::Error::Term(Box::new(crate::atoms::not_found()))),
+    }
+}
+
+#[rustler::nif]
+fn lvc_match<'a>(
+    env: rustler::Env<'a>,
+    lvc_resource: rustler::ResourceArc<LvcResource>,
+    key_expr: &str,
+) -> rustler::NifResult<(rustler::Atom, Vec<crate::sample::ZenohexSample<'a>>)> {
+    let samples = lvc_resource
+        .store
+        .intersecting(crate::keyexpr::new(key_expr)?)
+        .into_iter()
+        .map(|sample| crate::sample::ZenohexSample::from(env, sample))
+        .collect();
+
+    Ok((rustler::types::atom::ok(), samples))
+}
+
+#[rustler::nif]
+fn lvc_keys(
+    lvc_resource: rustler::ResourceArc<LvcResource>,
+) -> rustler::NifResult<(rustler::Atom, Vec<String>)> {
+    Ok((rustler::types::atom::ok(), lvc_resource.store.keys()))
+}
@@ -1,6 +1,7 @@
 use std::ops::Deref;
 use std::sync::Mutex;
 
+use rustler::Encoder;
 use zenoh::Wait;
 
 struct MatchingListenerResource {
@@ -102,16 +103,14 @@ fn matching_declare_listener(
     let session_locked = session.read().unwrap();
     let entity = session_locked.get_entity(entity_global_id)?;
 
-    let send_matching_status = move |matching_status| {
-        // WHY: Spawn a thread inside this callback.
-        //      If we don't spawn a thread, a panic will occur.
-        //      See: https://docs.rs/rustler/latest/rustler/env/struct.OwnedEnv.html#panics
-        std::thread::spawn(move || {
-            let _ = rustler::OwnedEnv::new().run(|env: rustler::Env| {
-                env.send(&pid, ZenohexMatchingStatus::from(matching_status))
-            });
-        });
-    };
+    let delivery = crate::helper::delivery::Delivery::spawn(
+        pid,
+        |env, matching_status: zenoh::matching::MatchingStatus| {
+            ZenohexMatchingStatus::from(matching_status).encode(env)
+        },
+    );
+
+    let send_matching_status = move |matching_status| delivery.push(matching_status);
 
     let listener = match entity {
         crate::session::Entity::Publisher(publisher, _) => publisher
@@ -0,0 +1,321 @@
+#[derive(rustler::NifUnitEnum)]
+enum PluginState {
+    Declared,
+    Loaded,
+    Started,
+}
+
+impl From<zenoh_plugin_trait::PluginState> for PluginState {
+    fn from(value: zenoh_plugin_trait::PluginState) -> Self {
+        match value {
+            zenoh_plugin_trait::PluginState::Declared => PluginState::Declared,
+            zenoh_plugin_trait::PluginState::Loaded => PluginState::Loaded,
+            zenoh_plugin_trait::PluginState::Started => PluginState::Started,
+        }
+    }
+}
+
+#[derive(rustler::NifUnitEnum)]
+enum PluginReportLevel {
+    Info,
+    Warning,
+    Error,
+}
+
+impl From<zenoh_plugin_trait::PluginReportLevel> for PluginReportLevel {
+    fn from(value: zenoh_plugin_trait::PluginReportLevel) -> Self {
+        match value {
+            zenoh_plugin_trait::PluginReportLevel::Info => PluginReportLevel::Info,
+            zenoh_plugin_trait::PluginReportLevel::Warning => PluginReportLevel::Warning,
+            zenoh_plugin_trait::PluginReportLevel::Error => PluginReportLevel::Error,
+        }
+    }
+}
+
+#[derive(rustler::NifStruct)]
+#[module = "Zenohex.Plugins.Report"]
+struct ZenohexPluginReport {
+    level: PluginReportLevel,
+    messages: Vec<String>,
+}
+
+#[derive(rustler::NifStruct)]
+#[module = "Zenohex.Plugins.Plugin"]
+struct ZenohexPlugin {
+    id: String,
+    name: String,
+    version: Option<String>,
+    long_version: Option<String>,
+    path: String,
+    state: PluginState,
+    report: ZenohexPluginReport,
+}
+
+impl ZenohexPlugin {
+    fn new(plugin: &dyn zenoh_plugin_trait::PluginStatus) -> Self {
+        let report = plugin.report();
+
+        ZenohexPlugin {
+            id: plugin.id().to_string(),
+            name: plugin.name().to_string(),
+            version: plugin.version().map(String::from),
+            long_version: plugin.long_version().map(String::from),
+            path: plugin.path().to_string(),
+            state: plugin.state().into(),
+            report: ZenohexPluginReport {
+                level: report.get_level().into(),
+                messages: report.messages().iter().map(|m| m.to_string()).collect(),
+            },
+        }
+    }
+}
+
+// WHY: Clone the runtime, so that the session lock is not held while plugins start or stop.
+fn get_runtime(
+    session_id: &zenoh::session::ZenohId,
+) -> rustler::NifResult<zenoh::internal::runtime::Runtime> {
+    let session =
+        crate::session::SessionMap::get_session(&crate::session::SESSION_MAP, session_id)?;
+    let session_locked = session.read().unwrap();
+
+    Ok(session_locked.runtime().clone())
+}
+
+// NOTE: The plugin config is inserted before locking the plugins manager,
+//       since the config validation of the runtime locks it too.
+fn insert_config(
+    runtime: &zenoh::internal::runtime::Runtime,
+    id: &str,
+    opts: rustler::Term,
+) -> rustler::NifResult<()> {
+    if let Some(term) = crate::helper::keyword::get_value(opts, crate::atoms::config())? {
+        let config: &str = term.decode()?;
+
+        runtime
+            .config()
+            .insert_json5(&format!("plugins/{id}"), config)
+            .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+    }
+
+    Ok(())
+}
+
+fn stop(runtime: &zenoh::internal::runtime::Runtime, id: &str) -> bool {
+    match runtime.plugins_manager().started_plugin_mut(id) {
+        Some(started) => {
+            started.stop();
+            true
+        }
+        None => false,
+    }
+}
+
+// NOTE: Same steps as the runtime does for the plugins of the config,
+//       a plugin which failed to load stays declared with its errors in its report.
+fn declare_and_load(
+    plugins_manager: &mut zenoh::internal::plugins::PluginsManager,
+    id: &str,
+    name: &str,
+    paths: Option<Vec<String>>,
+    required: bool,
+) -> zenoh::Result<()> {
+    if plugins_manager.plugin(id).is_none() {
+        // WHY: Statically linked plugins are preferred, unless paths to a library are given.
+        match (name, paths) {
+            #[cfg(feature = "plugin_rest")]
+            ("rest", None) => plugins_manager
+                .declare_static_plugin::<zenoh_plugin_rest::RestPlugin, _>(id, required),
+            #[cfg(feature = "plugin_storage_manager")]
+            ("storage_manager", None) => plugins_manager
+                .declare_static_plugin::<zenoh_plugin_storage_manager::StoragesPlugin, _>(
+                id, required,
+            ),
+            (name, Some(paths)) => {
+                plugins_manager.declare_dynamic_plugin_by_paths(name, id, &paths, required)?;
+            }
+            (name, None) => {
+                plugins_manager.declare_dynamic_plugin_by_name(id, name, required)?;
+            }
+        }
+    }
+
+    let declared = plugins_manager
+        .plugin_mut(id)
+        .ok_or("plugin not declared")?;
+
+    if declared.loaded().is_none() && declared.load()?.is_none() {
+        return Err("plugins loading is disabled".into());
+    }
+
+    Ok(())
+}
+
+// NOTE: Replaces the plugins loading of the runtime, to declare the statically linked plugins.
+//       Unlike the runtime, a required plugin which fails to load is an error, not a panic.
+pub fn new_plugins_manager(
+    config: &zenoh::Config,
+) -> zenoh::Result<zenoh::internal::plugins::PluginsManager> {
+    let mut plugins_manager = zenoh::internal::plugins::PluginsManager::dynamic(
+        config.libloader(),
+        zenoh::internal::plugins::PLUGIN_PREFIX,
+    );
+
+    for load in config.plugins().load_requests() {
+        let result = declare_and_load(
+            &mut plugins_manager,
+            &load.id,
+            &load.name,
+            load.paths,
+            load.required,
+        );
+
+        match result {
+            Err(error) if load.required => return Err(error),
+            Err(error) => log::error!("plugin {} load failed: {}", load.id, error),
+            Ok(()) => {}
+        }
+    }
+
+    Ok(plugins_manager)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn plugins_list(
+    session_id_resource: rustler::ResourceArc<crate::session::SessionIdResource>,
+) -> rustler::NifResult<(rustler::Atom, Vec<ZenohexPlugin>)> {
+    let runtime = get_runtime(&session_id_resource)?;
+
+    let plugins = runtime
+        .plugins_manager()
+        .declared_plugins_iter()
+        .map(|plugin| ZenohexPlugin::new(plugin.as_status()))
+        .collect();
+
+    Ok((rustler::types::atom::ok(), plugins))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn plugins_start(
+    session_id_resource: rustler::ResourceArc<crate::session::SessionIdResource>,
+    id: &str,
+    opts: rustler::Term,
+) -> rustler::NifResult<rustler::Atom> {
+    let runtime = get_runtime(&session_id_resource)?;
+
+    let name = match crate::helper::keyword::get_value(opts, crate::atoms::name())? {
+        Some(term) => term.decode::<String>()?,
+        None => id.to_string(),
+    };
+    let paths = match crate::helper::keyword::get_value(opts, crate::atoms::paths())? {
+        Some(term) => term.decode::<Option<Vec<String>>>()?,
+        None => None,
+    };
+    let required = match crate::helper::keyword::get_value(opts, crate::atoms::required())? {
+        Some(term) => term.decode::<bool>()?,
+        None => false,
+    };
+
+    insert_config(&runtime, id, opts)?;
+
+    let mut plugins_manager = runtime.plugins_manager();
+
+    declare_and_load(&mut plugins_manager, id, &name, paths, required)
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    let loaded = plugins_manager
+        .loaded_plugin_mut(id)
+        .ok_or_else(|| rustler::Error::Term(Box::new("plugin not loaded")))?;
+
+    if loaded.started().is_none() {
+        loaded
+            .start(&runtime.clone().into())
+            .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+    }
+
+    Ok(rustler::types::atom::ok())
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn plugins_stop(
+    session_id_resource: rustler::ResourceArc<crate::session::SessionIdResource>,
+    id: &str,
+) -> rustler::NifResult<rustler::Atom> {
+    let runtime = get_runtime(&session_id_resource)?;
+
+    if stop(&runtime, id) {
+        Ok(rustler::types::atom::ok())
+    } else {
+        Err(rustler::Error::Term(Box::new("plugin not started")))
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn plugins_reload(
+    session_id_resource: rustler::ResourceArc<crate::session::SessionIdResource>,
+    id: &str,
+    opts: rustler::Term,
+) -> rustler::NifResult<rustler::Atom> {
+    let runtime = get_runtime(&session_id_resource)?;
+
+    if runtime.plugins_manager().loaded_plugin(id).is_none() {
+        return Err(rustler::Error::Term(Box::new("plugin not loaded")));
+    }
+
+    stop(&runtime, id);
+    insert_config(&runtime, id, opts)?;
+
+    let mut plugins_manager = runtime.plugins_manager();
+    let loaded = plugins_manager
+        .loaded_plugin_mut(id)
+        .ok_or_else(|| rustler::Error::Term(Box::new("plugin not loaded")))?;
+
+    loaded
+        .start(&runtime.clone().into())
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    Ok(rustler::types::atom::ok())
+}
+
+// NOTE: Same replies as the runtime gives under `@/<zid>/<whatami>/status/plugins/<id>/**`,
+//       read from the plugins manager, so that the admin space need not be enabled.
+//       Plugins reply JSON, except for `__path__` which is plain text,
+//       so the payloads are decoded on the Elixir side.
+#[rustler::nif(schedule = "DirtyIo")]
+fn plugins_status(
+    session_id_resource: rustler::ResourceArc<crate::session::SessionIdResource>,
+    id: &str,
+) -> rustler::NifResult<(rustler::Atom, Vec<(String, String)>)> {
+    let runtime = get_runtime(&session_id_resource)?;
+    let plugins_manager = runtime.plugins_manager();
+
+    let Some(started) = plugins_manager.started_plugin(id) else {
+        return Ok((rustler::types::atom::ok(), Vec::new()));
+    };
+
+    let whatami = runtime.whatami().to_str();
+    let plugin_key = format!("@/{}/{whatami}/status/plugins/{id}", runtime.zid());
+    let key_expr = zenoh::key_expr::KeyExpr::try_from(format!("{plugin_key}/**"))
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    let responses = started
+        .instance()
+        .adminspace_getter(&key_expr, &plugin_key)
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    let mut status = vec![("__path__".to_string(), started.path().to_string())];
+
+    for response in responses {
+        let key = match response.key.strip_prefix(&plugin_key) {
+            Some("") => String::new(),
+            Some(rest) => match rest.strip_prefix('/') {
+                Some(key) => key.to_string(),
+                None => continue,
+            },
+            None => continue,
+        };
+
+        status.push((key, response.value.to_string()));
+    }
+
+    Ok((rustler::types::atom::ok(), status))
+}
@@ -5,7 +5,7 @@ use crate::builder::Builder;
 #[rustler::nif]
 fn publisher_put(
     entity_global_id_resource: rustler::ResourceArc<crate::session::EntityGlobalIdResource>,
-    payload: rustler::Binary,
+    payload: rustler::Term,
     opts: rustler::Term,
 ) -> rustler::NifResult<rustler::Atom> {
     let session_id = &entity_global_id_resource.zid();
@@ -19,7 +19,16 @@ fn publisher_put(
     match entity {
         crate::session::Entity::Publisher(publisher, _) => {
             publisher
-                .put(payload.as_slice())
+                .put(crate::shm::decode_payload(payload)?)
+                .apply_opts(opts)?
+                .wait()
+                .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+            Ok(rustler::types::atom::ok())
+        }
+        crate::session::Entity::AdvancedPublisher(publisher, _) => {
+            publisher
+                .put(crate::shm::decode_payload(payload)?)
                 .apply_opts(opts)?
                 .wait()
                 .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
@@ -55,6 +64,15 @@ fn publisher_delete(
 
             Ok(rustler::types::atom::ok())
         }
+        crate::session::Entity::AdvancedPublisher(publisher, _) => {
+            publisher
+                .delete()
+                .apply_opts(opts)?
+                .wait()
+                .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+            Ok(rustler::types::atom::ok())
+        }
         _ => Err(rustler::Error::Term(Box::new(
             crate::atoms::unsupported_entity(),
         ))),
@@ -74,7 +92,7 @@ fn publisher_undeclare(
 
     let is_publisher = matches!(
         session_locked.get_entity(entity_global_id)?,
-        crate::session::Entity::Publisher(_, _)
+        crate::session::Entity::Publisher(_, _) | crate::session::Entity::AdvancedPublisher(_, _)
     );
 
     if !is_publisher {
@@ -83,15 +101,12 @@ fn publisher_undeclare(
         )));
     }
 
-    let entity = session_locked.remove_entity(entity_global_id)?;
-    let crate::session::Entity::Publisher(publisher, _) = entity else {
-        unreachable!("entity kind changed after publisher check")
-    };
-
-    publisher
-        .undeclare()
-        .wait()
-        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+    match session_locked.remove_entity(entity_global_id)? {
+        crate::session::Entity::Publisher(publisher, _) => publisher.undeclare().wait(),
+        crate::session::Entity::AdvancedPublisher(publisher, _) => publisher.undeclare().wait(),
+        _ => unreachable!("entity kind changed after publisher check"),
+    }
+    .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
 
     Ok(rustler::types::atom::ok())
 }
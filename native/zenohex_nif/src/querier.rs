@@ -1,7 +1,6 @@
 use std::time::Duration;
 use std::time::Instant;
 
-use rustler::Encoder;
 use zenoh::Wait;
 
 use crate::builder::Builder;
@@ -66,14 +65,7 @@ fn querier_get<'a>(
             }
         };
 
-        let term = match reply.result() {
-            Ok(sample) => crate::sample::ZenohexSample::from(env, sample.clone()).encode(env),
-            Err(reply_error) => {
-                crate::query::ZenohexQueryReplyError::from(env, reply_error.clone()).encode(env)
-            }
-        };
-
-        replies.push(term);
+        replies.push(crate::query::encode_reply(env, reply));
     }
 
     Ok((rustler::types::atom::ok(), replies))
@@ -83,6 +75,7 @@ fn querier_get<'a>(
 fn querier_get_async(
     entity_global_id_resource: rustler::ResourceArc<crate::session::EntityGlobalIdResource>,
     pid: rustler::LocalPid,
+    reference: rustler::Term,
     opts: rustler::Term,
 ) -> rustler::NifResult<rustler::Atom> {
     let session_id = &entity_global_id_resource.zid();
@@ -94,30 +87,18 @@ fn querier_get_async(
     let entity = session_locked.get_entity(entity_global_id)?;
 
     match entity {
-        crate::session::Entity::Querier(querier, _) => querier
-            .get()
-            .apply_opts(opts)?
-            .callback(move |reply| {
-                // WHY: Spawn a thread inside this callback.
-                //      If we don't spawn a thread, a panic will occur.
-                //      See: https://docs.rs/rustler/latest/rustler/env/struct.OwnedEnv.html#panics
-                std::thread::spawn(move || {
-                    let _ = rustler::OwnedEnv::new().run(|env: rustler::Env| {
-                        let term = match reply.result() {
-                            Ok(sample) => {
-                                crate::sample::ZenohexSample::from(env, sample.clone()).encode(env)
-                            }
-                            Err(reply_error) => {
-                                crate::query::ZenohexQueryReplyError::from(env, reply_error.clone())
-                                    .encode(env)
-                            }
-                        };
-                        env.send(&pid, term)
-                    });
-                });
-            })
-            .wait()
-            .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?,
+        crate::session::Entity::Querier(querier, _) => {
+            let (delivery, get_started) = crate::query::get_async_delivery(pid, reference, opts)?;
+
+            querier
+                .get()
+                .apply_opts(opts)?
+                .callback(move |reply| delivery.push(reply))
+                .wait()
+                .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+            get_started.started();
+        }
         _ => {
             return Err(rustler::Error::Term(Box::new(
                 crate::atoms::unsupported_entity(),
@@ -128,6 +109,49 @@ fn querier_get_async(
     Ok(rustler::types::atom::ok())
 }
 
+#[rustler::nif]
+fn querier_get_pull(
+    entity_global_id_resource: rustler::ResourceArc<crate::session::EntityGlobalIdResource>,
+    handler: crate::receiver::Handler,
+    opts: rustler::Term,
+) -> rustler::NifResult<(
+    rustler::Atom,
+    rustler::ResourceArc<crate::receiver::ReceiverResource>,
+)> {
+    let session_id = &entity_global_id_resource.zid();
+    let entity_global_id = &entity_global_id_resource;
+
+    let session =
+        crate::session::SessionMap::get_session(&crate::session::SESSION_MAP, session_id)?;
+    let session_locked = session.read().unwrap();
+    let entity = session_locked.get_entity(entity_global_id)?;
+
+    let receiver = match entity {
+        crate::session::Entity::Querier(querier, _) => {
+            let (callback, receiver) = handler.into_handler()?;
+
+            querier
+                .get()
+                .apply_opts(opts)?
+                .callback(move |reply| callback.call(reply))
+                .wait()
+                .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+            receiver
+        }
+        _ => {
+            return Err(rustler::Error::Term(Box::new(
+                crate::atoms::unsupported_entity(),
+            )))
+        }
+    };
+
+    Ok((
+        rustler::types::atom::ok(),
+        rustler::ResourceArc::new(crate::receiver::ReceiverResource::Reply(receiver)),
+    ))
+}
+
 #[rustler::nif]
 fn querier_undeclare(
     entity_global_id_resource: rustler::ResourceArc<crate::session::EntityGlobalIdResource>,
@@ -2,6 +2,7 @@ use std::io::Write;
 use std::ops::Deref;
 use std::sync::Mutex;
 
+use rustler::Encoder;
 use zenoh::Wait;
 
 use crate::builder::Builder;
@@ -34,6 +35,7 @@ pub struct ZenohexQuery<'a> {
     parameters: String,
     payload: Option<rustler::Binary<'a>>,
     selector: String,
+    source_info: Option<crate::sample::ZenohexSourceInfo>,
     zenoh_query: rustler::ResourceArc<QueryResource>,
 }
 
@@ -70,6 +72,9 @@ impl<'a> ZenohexQuery<'a> {
             parameters: query.parameters().to_string(),
             payload,
             selector: query.selector().to_string(),
+            source_info: query
+                .source_info()
+                .map(crate::sample::ZenohexSourceInfo::from),
             zenoh_query: rustler::ResourceArc::new(QueryResource::new(query)),
         }
     }
@@ -99,10 +104,82 @@ impl<'a> ZenohexQueryReplyError<'a> {
     }
 }
 
+pub fn encode_reply<'a>(env: rustler::Env<'a>, reply: zenoh::query::Reply) -> rustler::Term<'a> {
+    match reply.into_result() {
+        Ok(sample) => crate::sample::ZenohexSample::from(env, sample).encode(env),
+        Err(reply_error) => ZenohexQueryReplyError::from(env, reply_error).encode(env),
+    }
+}
+
+// Marks the get of a `get_async_delivery` as started, see `get_async_delivery`.
+pub struct GetAsyncStarted(std::sync::mpsc::Sender<()>);
+
+impl GetAsyncStarted {
+    pub fn started(self) {
+        // The worker thread only exits after receiving this, so this never fails.
+        let _ = self.0.send(());
+    }
+}
+
+// Builds the delivery used by async gets.
+// Every reply is tagged with `reference`, and `{:zenohex_get_done, reference}` is sent
+// once zenoh drops the reply callback, which means that no more replies will follow.
+// WHY: The callback is also dropped when the get fails to start, then the NIF returns
+//      an error and the caller never gets `reference`. So done is only sent once
+//      `GetAsyncStarted::started` is called, and not if it is dropped before.
+// WHY: Cancelling the token of the get only waits for the running reply callbacks,
+//      so the replies they have already queued are dropped here.
+pub fn get_async_delivery(
+    pid: rustler::LocalPid,
+    reference: rustler::Term,
+    opts: rustler::Term,
+) -> rustler::NifResult<(
+    crate::helper::delivery::Delivery<zenoh::query::Reply>,
+    GetAsyncStarted,
+)> {
+    let reference = crate::helper::term::EncodedTerm::new(reference);
+    let done_reference = reference.clone();
+    let (started_tx, started_rx) = std::sync::mpsc::channel();
+    let cancellation_token =
+        match crate::helper::keyword::get_value(opts, crate::atoms::cancellation_token())? {
+            Some(term) => {
+                let cancellation_token: rustler::ResourceArc<
+                    crate::cancellation::CancellationTokenResource,
+                > = term.decode()?;
+                Some((**cancellation_token).clone())
+            }
+            None => None,
+        };
+
+    let delivery = crate::helper::delivery::Delivery::spawn_with_done(
+        pid,
+        move |env, reply: zenoh::query::Reply| {
+            (
+                crate::atoms::zenohex_get_reply(),
+                reference.decode(env),
+                encode_reply(env, reply),
+            )
+                .encode(env)
+        },
+        move |env| {
+            started_rx.recv().ok().map(|()| {
+                (crate::atoms::zenohex_get_done(), done_reference.decode(env)).encode(env)
+            })
+        },
+        move || {
+            cancellation_token
+                .as_ref()
+                .is_some_and(zenoh::cancellation::CancellationToken::is_cancelled)
+        },
+    );
+
+    Ok((delivery, GetAsyncStarted(started_tx)))
+}
+
 #[rustler::nif]
 fn query_reply(
     query_resource: rustler::ResourceArc<QueryResource>,
-    key_expr: &str,
+    key_expr: crate::keyexpr::KeyExprArg,
     payload: rustler::Binary,
     opts: rustler::Term,
 ) -> rustler::NifResult<rustler::Atom> {
@@ -139,7 +216,7 @@ fn query_reply_error(
 #[rustler::nif]
 fn query_reply_delete(
     query_resource: rustler::ResourceArc<QueryResource>,
-    key_expr: &str,
+    key_expr: crate::keyexpr::KeyExprArg,
     opts: rustler::Term,
 ) -> rustler::NifResult<rustler::Atom> {
     handle_reply(query_resource, opts, |query| {
@@ -0,0 +1,197 @@
+use std::time::Duration;
+use std::time::Instant;
+
+use rustler::Encoder;
+use zenoh::handlers::IntoHandler;
+
+#[derive(rustler::NifTaggedEnum)]
+pub enum Handler {
+    Fifo(usize),
+    Ring(usize),
+}
+
+impl Handler {
+    pub fn into_handler<T: Send + 'static>(
+        self,
+    ) -> rustler::NifResult<(zenoh::handlers::Callback<T>, Receiver<T>)> {
+        match self {
+            Handler::Fifo(0) | Handler::Ring(0) => Err(rustler::Error::RaiseTerm(Box::new(
+                crate::helper::exception::ArgumentError {
+                    message: "handler capacity must be a positive integer".to_string(),
+                },
+            ))),
+            Handler::Fifo(capacity) => {
+                // WHY: zenoh's FifoChannel blocks the thread delivering an item until there is
+                //      room. For a put or get of the same session, that thread is the BEAM
+                //      scheduler running the NIF, so a full FIFO drops the new item instead.
+                let (sender, receiver) = flume::bounded(capacity);
+                let callback = zenoh::handlers::Callback::from(move |item| {
+                    if let Err(flume::TrySendError::Full(_)) = sender.try_send(item) {
+                        log::debug!("fifo handler is full, dropping the new item");
+                    }
+                });
+                Ok((callback, Receiver::Fifo(receiver)))
+            }
+            Handler::Ring(capacity) => {
+                let (callback, handler) =
+                    zenoh::handlers::RingChannel::new(capacity).into_handler();
+                Ok((callback, Receiver::Ring(handler)))
+            }
+        }
+    }
+}
+
+pub enum Receiver<T> {
+    Fifo(flume::Receiver<T>),
+    Ring(zenoh::handlers::RingChannelHandler<T>),
+}
+
+impl<T> Receiver<T> {
+    fn try_recv(&self) -> zenoh::Result<Option<T>> {
+        match self {
+            Receiver::Fifo(receiver) => match receiver.try_recv() {
+                Ok(item) => Ok(Some(item)),
+                Err(flume::TryRecvError::Empty) => Ok(None),
+                Err(error) => Err(error.into()),
+            },
+            Receiver::Ring(handler) => handler.try_recv(),
+        }
+    }
+
+    fn recv_deadline(&self, deadline: Instant) -> zenoh::Result<Option<T>> {
+        match self {
+            Receiver::Fifo(receiver) => match receiver.recv_deadline(deadline) {
+                Ok(item) => Ok(Some(item)),
+                Err(flume::RecvTimeoutError::Timeout) => Ok(None),
+                Err(error) => Err(error.into()),
+            },
+            Receiver::Ring(handler) => handler.recv_deadline(deadline),
+        }
+    }
+}
+
+pub enum ReceiverResource {
+    Sample(Receiver<zenoh::sample::Sample>),
+    Query(Receiver<zenoh::query::Query>),
+    Reply(Receiver<zenoh::query::Reply>),
+}
+
+#[rustler::resource_impl]
+impl rustler::Resource for ReceiverResource {}
+
+impl ReceiverResource {
+    fn try_recv<'a>(&self, env: rustler::Env<'a>) -> zenoh::Result<Option<rustler::Term<'a>>> {
+        let term = match self {
+            ReceiverResource::Sample(receiver) => receiver
+                .try_recv()?
+                .map(|sample| crate::sample::ZenohexSample::from(env, sample).encode(env)),
+            ReceiverResource::Query(receiver) => receiver
+                .try_recv()?
+                .map(|query| crate::query::ZenohexQuery::from(env, query).encode(env)),
+            ReceiverResource::Reply(receiver) => receiver
+                .try_recv()?
+                .map(|reply| crate::query::encode_reply(env, reply)),
+        };
+
+        Ok(term)
+    }
+
+    fn recv_deadline<'a>(
+        &self,
+        env: rustler::Env<'a>,
+        deadline: Instant,
+    ) -> zenoh::Result<Option<rustler::Term<'a>>> {
+        let term = match self {
+            ReceiverResource::Sample(receiver) => receiver
+                .recv_deadline(deadline)?
+                .map(|sample| crate::sample::ZenohexSample::from(env, sample).encode(env)),
+            ReceiverResource::Query(receiver) => receiver
+                .recv_deadline(deadline)?
+                .map(|query| crate::query::ZenohexQuery::from(env, query).encode(env)),
+            ReceiverResource::Reply(receiver) => receiver
+                .recv_deadline(deadline)?
+                .map(|reply| crate::query::encode_reply(env, reply)),
+        };
+
+        Ok(term)
+    }
+}
+
+// WHY: Accept both an entity id and a bare receiver.
+//      Pull subscribers and queryables are entities and keep their receiver
+//      in the entity resource, while pull gets only return a receiver.
+fn decode_receiver(
+    term: rustler::Term,
+) -> rustler::NifResult<rustler::ResourceArc<ReceiverResource>> {
+    if let Ok(receiver) = term.decode::<rustler::ResourceArc<ReceiverResource>>() {
+        return Ok(receiver);
+    }
+
+    let entity_global_id_resource =
+        term.decode::<rustler::ResourceArc<crate::session::EntityGlobalIdResource>>()?;
+
+    entity_global_id_resource
+        .receiver()
+        .ok_or_else(|| rustler::Error::Term(Box::new(crate::atoms::unsupported_entity())))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn receiver_recv<'a>(
+    env: rustler::Env<'a>,
+    receiver: rustler::Term<'a>,
+    timeout: u64,
+) -> rustler::NifResult<(rustler::Atom, rustler::Term<'a>)> {
+    let receiver = decode_receiver(receiver)?;
+    let deadline = Instant::now() + Duration::from_millis(timeout);
+
+    match receiver.recv_deadline(env, deadline) {
+        Ok(Some(term)) => Ok((rustler::types::atom::ok(), term)),
+        Ok(None) => Err(rustler::Error::Term(Box::new(crate::atoms::timeout()))),
+        Err(error) => Err(rustler::Error::Term(crate::zenoh_error!(error))),
+    }
+}
+
+#[rustler::nif]
+fn receiver_try_recv<'a>(
+    env: rustler::Env<'a>,
+    receiver: rustler::Term<'a>,
+) -> rustler::NifResult<(rustler::Atom, rustler::Term<'a>)> {
+    let receiver = decode_receiver(receiver)?;
+
+    match receiver.try_recv(env) {
+        Ok(Some(term)) => Ok((rustler::types::atom::ok(), term)),
+        Ok(None) => Err(rustler::Error::Term(Box::new(crate::atoms::empty()))),
+        Err(error) => Err(rustler::Error::Term(crate::zenoh_error!(error))),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn receiver_recv_many<'a>(
+    env: rustler::Env<'a>,
+    receiver: rustler::Term<'a>,
+    max: usize,
+    timeout: u64,
+) -> rustler::NifResult<(rustler::Atom, Vec<rustler::Term<'a>>)> {
+    let receiver = decode_receiver(receiver)?;
+    let deadline = Instant::now() + Duration::from_millis(timeout);
+    let mut terms = Vec::new();
+
+    while terms.len() < max {
+        match receiver.recv_deadline(env, deadline) {
+            Ok(Some(term)) => terms.push(term),
+            // NOTE: Returning fewer than `max` terms, or none, on timeout is not an error.
+            //       The caller asks again with its remaining demand.
+            Ok(None) => break,
+            Err(error) => {
+                // If the channel disconnected after receiving some terms,
+                // return what we collected and report the error on the next call.
+                if !terms.is_empty() {
+                    break;
+                }
+                return Err(rustler::Error::Term(crate::zenoh_error!(error)));
+            }
+        }
+    }
+
+    Ok((rustler::types::atom::ok(), terms))
+}
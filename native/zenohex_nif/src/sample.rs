@@ -26,7 +26,10 @@ pub struct ZenohexSample<'a> {
     kind: SampleKind,
     payload: rustler::Binary<'a>,
     priority: crate::builder::Priority,
-    timestamp: Option<String>,
+    reliability: crate::builder::Reliability,
+    shm: bool,
+    source_info: Option<ZenohexSourceInfo>,
+    timestamp: Option<crate::timestamp::ZenohexTimestamp>,
 }
 
 impl<'a> ZenohexSample<'a> {
@@ -56,7 +59,7 @@ impl<'a> ZenohexSample<'a> {
 
         let timestamp = sample
             .timestamp()
-            .map(|timestamp| timestamp.to_string_rfc3339_lossy());
+            .map(crate::timestamp::ZenohexTimestamp::from);
 
         ZenohexSample {
             attachment,
@@ -67,7 +70,52 @@ impl<'a> ZenohexSample<'a> {
             kind: sample.kind().into(),
             payload,
             priority: sample.priority().into(),
+            reliability: sample.reliability().into(),
+            shm: sample.payload().as_shm().is_some(),
+            source_info: sample.source_info().map(ZenohexSourceInfo::from),
             timestamp,
         }
     }
 }
+
+#[derive(rustler::NifStruct)]
+#[module = "Zenohex.SampleMiss"]
+pub struct ZenohexSampleMiss {
+    source: crate::entity_global_id::ZenohexEntityGlobalId,
+    nb: u32,
+}
+
+impl ZenohexSampleMiss {
+    pub fn from(miss: zenoh_ext::Miss) -> Self {
+        ZenohexSampleMiss {
+            source: (&miss.source()).into(),
+            nb: miss.nb(),
+        }
+    }
+}
+
+#[derive(rustler::NifStruct)]
+#[module = "Zenohex.SourceInfo"]
+pub struct ZenohexSourceInfo {
+    source_id: crate::entity_global_id::ZenohexEntityGlobalId,
+    source_sn: u32,
+}
+
+impl From<&zenoh::sample::SourceInfo> for ZenohexSourceInfo {
+    fn from(source_info: &zenoh::sample::SourceInfo) -> Self {
+        ZenohexSourceInfo {
+            source_id: source_info.source_id().into(),
+            source_sn: source_info.source_sn(),
+        }
+    }
+}
+
+impl TryFrom<ZenohexSourceInfo> for zenoh::sample::SourceInfo {
+    type Error = rustler::Error;
+
+    fn try_from(value: ZenohexSourceInfo) -> Result<Self, Self::Error> {
+        let source_id = value.source_id.try_into()?;
+
+        Ok(zenoh::sample::SourceInfo::new(source_id, value.source_sn))
+    }
+}
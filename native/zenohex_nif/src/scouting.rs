@@ -3,6 +3,7 @@ use std::sync::RwLock;
 use std::time::Duration;
 use std::time::Instant;
 
+use rustler::Encoder;
 use zenoh::Wait;
 
 #[derive(rustler::NifStruct)]
@@ -116,16 +117,12 @@ fn scouting_declare_scout(
     let config = zenoh::Config::from_json5(json5_binary)
         .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
 
+    let delivery = crate::helper::delivery::Delivery::spawn(pid, |env, hello| {
+        ZenohexScoutingHello::from(hello).encode(env)
+    });
+
     let scout = zenoh::scout(zenoh::config::WhatAmI::from(what), config)
-        .callback(move |hello| {
-            // WHY: Spawn a thread inside this callback.
-            //      If we don't spawn a thread, a panic will occur.
-            //      See: https://docs.rs/rustler/latest/rustler/env/struct.OwnedEnv.html#panics
-            std::thread::spawn(move || {
-                let _ = rustler::OwnedEnv::new()
-                    .run(|env: rustler::Env| env.send(&pid, ZenohexScoutingHello::from(hello)));
-            });
-        })
+        .callback(move |hello| delivery.push(hello))
         .wait()
         .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
 
@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::SystemTime;
+
+use zenoh::query::ZenohParameters;
+
+// A selector argument, either a selector string, e.g. `"demo/**?limit=10"`,
+// or a key expression declared by `Zenohex.Session.declare_keyexpr/2`.
+pub struct SelectorArg(zenoh::query::Selector<'static>);
+
+impl<'a> rustler::Decoder<'a> for SelectorArg {
+    fn decode(term: rustler::Term<'a>) -> rustler::NifResult<Self> {
+        if term.is_binary() {
+            return zenoh::query::Selector::try_from(term.decode::<String>()?)
+                .map(SelectorArg)
+                .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)));
+        }
+
+        let key_expr: zenoh::key_expr::KeyExpr<'static> =
+            term.decode::<crate::keyexpr::KeyExprArg>()?.into();
+
+        Ok(SelectorArg(key_expr.into()))
+    }
+}
+
+impl SelectorArg {
+    // Appends the `parameters` option to the parameters of the selector, if any.
+    pub fn with_opts(
+        self,
+        opts: rustler::Term,
+    ) -> rustler::NifResult<zenoh::query::Selector<'static>> {
+        let Some(term) = crate::helper::keyword::get_value(opts, crate::atoms::parameters())?
+        else {
+            return Ok(self.0);
+        };
+
+        let extra = term.decode::<String>()?;
+        let (key_expr, parameters) = self.0.split();
+        let parameters = if parameters.is_empty() {
+            extra
+        } else {
+            format!("{parameters};{extra}")
+        };
+
+        Ok(zenoh::query::Selector::owned(
+            key_expr,
+            zenoh::query::Parameters::from(parameters),
+        ))
+    }
+}
+
+#[derive(rustler::NifTaggedEnum)]
+enum TimeExpr {
+    Fixed(i64),
+    Now(f64),
+}
+
+impl TryFrom<zenoh::query::TimeExpr> for TimeExpr {
+    type Error = rustler::Error;
+
+    fn try_from(time_expr: zenoh::query::TimeExpr) -> Result<Self, Self::Error> {
+        match time_expr {
+            zenoh::query::TimeExpr::Fixed(time) => Ok(TimeExpr::Fixed(unix_nanos(time)?)),
+            zenoh::query::TimeExpr::Now { offset_secs } => Ok(TimeExpr::Now(offset_secs)),
+        }
+    }
+}
+
+// NOTE: Times before the UNIX epoch are valid RFC3339 instants, hence the signed nanoseconds.
+// WHY: i64 nanoseconds only span the years 1677 to 2262, so times out of them are an error
+//      rather than a wrapped around instant.
+fn unix_nanos(time: SystemTime) -> rustler::NifResult<i64> {
+    let nanos = match time.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(duration) => i64::try_from(duration.as_nanos()).ok(),
+        Err(error) => i64::try_from(error.duration().as_nanos())
+            .ok()
+            .map(|nanos| -nanos),
+    };
+
+    nanos.ok_or_else(|| {
+        rustler::Error::Term(Box::new(format!(
+            "time {time:?} is out of the range of i64 nanoseconds since the UNIX epoch"
+        )))
+    })
+}
+
+#[derive(rustler::NifTaggedEnum)]
+enum TimeBound {
+    Inclusive(TimeExpr),
+    Exclusive(TimeExpr),
+    Unbounded,
+}
+
+impl TryFrom<zenoh::query::TimeBound<zenoh::query::TimeExpr>> for TimeBound {
+    type Error = rustler::Error;
+
+    fn try_from(
+        time_bound: zenoh::query::TimeBound<zenoh::query::TimeExpr>,
+    ) -> Result<Self, Self::Error> {
+        match time_bound {
+            zenoh::query::TimeBound::Inclusive(time_expr) => {
+                Ok(TimeBound::Inclusive(time_expr.try_into()?))
+            }
+            zenoh::query::TimeBound::Exclusive(time_expr) => {
+                Ok(TimeBound::Exclusive(time_expr.try_into()?))
+            }
+            zenoh::query::TimeBound::Unbounded => Ok(TimeBound::Unbounded),
+        }
+    }
+}
+
+#[derive(rustler::NifStruct)]
+#[module = "Zenohex.Selector.TimeRange"]
+struct ZenohexTimeRange {
+    start: TimeBound,
+    end: TimeBound,
+}
+
+impl TryFrom<zenoh::query::TimeRange> for ZenohexTimeRange {
+    type Error = rustler::Error;
+
+    fn try_from(time_range: zenoh::query::TimeRange) -> Result<Self, Self::Error> {
+        Ok(ZenohexTimeRange {
+            start: time_range.start.try_into()?,
+            end: time_range.end.try_into()?,
+        })
+    }
+}
+
+#[rustler::nif]
+fn selector_split(selector: &str) -> rustler::NifResult<(rustler::Atom, (String, String))> {
+    let selector = zenoh::query::Selector::try_from(selector)
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+    let (key_expr, parameters) = selector.split();
+
+    Ok((
+        rustler::types::atom::ok(),
+        (key_expr.to_string(), parameters.to_string()),
+    ))
+}
+
+// NOTE: Like `Parameters::get`, the first entry wins when a key is repeated.
+#[rustler::nif]
+fn selector_parameters_parse(parameters: &str) -> HashMap<String, String> {
+    let parameters = zenoh::query::Parameters::from(parameters);
+    let mut map = HashMap::new();
+
+    for (key, value) in parameters.iter() {
+        map.entry(key.to_string())
+            .or_insert_with(|| value.to_string());
+    }
+
+    map
+}
+
+#[rustler::nif]
+fn selector_parameters_values(parameters: &str, key: &str) -> Vec<String> {
+    zenoh::query::Parameters::from(parameters)
+        .values(key)
+        .map(String::from)
+        .collect()
+}
+
+// WHY: The parameters syntax has no escape sequence, so separators which would be
+//      read back as another entry or another value are rejected instead of being mangled.
+#[rustler::nif]
+fn selector_parameters_build(
+    entries: Vec<(String, Vec<String>)>,
+) -> rustler::NifResult<(rustler::Atom, String)> {
+    let mut parameters = zenoh::query::Parameters::empty();
+
+    for (key, values) in entries {
+        if key.is_empty() || key.contains([';', '=']) {
+            return Err(rustler::Error::Term(Box::new(format!(
+                "invalid parameter key {key:?}: must be non-empty and contain neither ';' nor '='"
+            ))));
+        }
+
+        if let Some(value) = values.iter().find(|value| value.contains([';', '|'])) {
+            return Err(rustler::Error::Term(Box::new(format!(
+                "invalid parameter value {value:?} for {key:?}: must contain neither ';' nor '|'"
+            ))));
+        }
+
+        parameters.insert(key, values.join("|"));
+    }
+
+    Ok((rustler::types::atom::ok(), parameters.to_string()))
+}
+
+// WHY: zenoh panics on some malformed time ranges, e.g. `[now(s)..]` or a negative duration
+//      `[2020-01-01T00:00:00Z;-1s]`. `_time` comes from the selectors of remote queries,
+//      so a panic is turned into an error.
+fn catch_time_range_panic<T, E: std::fmt::Display>(
+    parse: impl FnOnce() -> Result<T, E> + std::panic::UnwindSafe,
+) -> rustler::NifResult<T> {
+    match std::panic::catch_unwind(parse) {
+        Ok(result) => result.map_err(|error| rustler::Error::Term(crate::zenoh_error!(error))),
+        Err(_panic) => Err(rustler::Error::Term(Box::new("invalid time range"))),
+    }
+}
+
+#[rustler::nif]
+fn selector_parameters_time_range(
+    parameters: &str,
+) -> rustler::NifResult<(rustler::Atom, Option<ZenohexTimeRange>)> {
+    let time_range = catch_time_range_panic(|| {
+        zenoh::query::Parameters::from(parameters)
+            .time_range()
+            .transpose()
+    })?;
+
+    Ok((
+        rustler::types::atom::ok(),
+        time_range.map(ZenohexTimeRange::try_from).transpose()?,
+    ))
+}
+
+#[rustler::nif]
+fn selector_time_range_parse(
+    time_range: &str,
+) -> rustler::NifResult<(rustler::Atom, ZenohexTimeRange)> {
+    let time_range = catch_time_range_panic(|| zenoh::query::TimeRange::from_str(time_range))?;
+
+    Ok((rustler::types::atom::ok(), time_range.try_into()?))
+}
@@ -0,0 +1,243 @@
+use std::io::Write;
+
+use rustler::Encoder;
+
+#[derive(Clone, Copy, rustler::NifUnitEnum)]
+enum Scalar {
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    F32,
+    F64,
+    Bool,
+    String,
+    Bytes,
+}
+
+// NOTE: Type specs mirror the zenoh-ext serialization types,
+//       e.g. `{:tuple, [:i32, {:list, :f64}]}` corresponds to `(i32, Vec<f64>)`.
+//       See: https://docs.rs/zenoh-ext/latest/zenoh_ext/fn.z_serialize.html
+enum Type {
+    Scalar(Scalar),
+    Tuple(Vec<Type>),
+    List(Box<Type>),
+    Map(Box<Type>, Box<Type>),
+}
+
+impl<'a> rustler::Decoder<'a> for Type {
+    fn decode(term: rustler::Term<'a>) -> rustler::NifResult<Self> {
+        if let Ok(scalar) = term.decode::<Scalar>() {
+            return Ok(Type::Scalar(scalar));
+        }
+
+        let elements = rustler::types::tuple::get_tuple(term)?;
+
+        match elements.as_slice() {
+            [tag, types] if tag.decode::<rustler::Atom>()? == crate::atoms::tuple() => {
+                Ok(Type::Tuple(types.decode()?))
+            }
+            [tag, element] if tag.decode::<rustler::Atom>()? == crate::atoms::list() => {
+                Ok(Type::List(Box::new(element.decode()?)))
+            }
+            [tag, key, value] if tag.decode::<rustler::Atom>()? == crate::atoms::map() => Ok(
+                Type::Map(Box::new(key.decode()?), Box::new(value.decode()?)),
+            ),
+            _ => Err(rustler::Error::BadArg),
+        }
+    }
+}
+
+impl Type {
+    // Only empty tuples, and tuples of them, are serialized into no bytes.
+    fn consumes_bytes(&self) -> bool {
+        match self {
+            Type::Tuple(types) => types.iter().any(Type::consumes_bytes),
+            _ => true,
+        }
+    }
+}
+
+fn serialize_term(
+    serializer: &mut zenoh_ext::ZSerializer,
+    r#type: &Type,
+    term: rustler::Term,
+) -> rustler::NifResult<()> {
+    match r#type {
+        Type::Scalar(Scalar::U8) => serializer.serialize(term.decode::<u8>()?),
+        Type::Scalar(Scalar::U16) => serializer.serialize(term.decode::<u16>()?),
+        Type::Scalar(Scalar::U32) => serializer.serialize(term.decode::<u32>()?),
+        Type::Scalar(Scalar::U64) => serializer.serialize(term.decode::<u64>()?),
+        Type::Scalar(Scalar::I8) => serializer.serialize(term.decode::<i8>()?),
+        Type::Scalar(Scalar::I16) => serializer.serialize(term.decode::<i16>()?),
+        Type::Scalar(Scalar::I32) => serializer.serialize(term.decode::<i32>()?),
+        Type::Scalar(Scalar::I64) => serializer.serialize(term.decode::<i64>()?),
+        Type::Scalar(Scalar::F32) => serializer.serialize(term.decode::<f64>()? as f32),
+        Type::Scalar(Scalar::F64) => serializer.serialize(term.decode::<f64>()?),
+        Type::Scalar(Scalar::Bool) => serializer.serialize(term.decode::<bool>()?),
+        Type::Scalar(Scalar::String) => serializer.serialize(term.decode::<&str>()?),
+        Type::Scalar(Scalar::Bytes) => {
+            serializer.serialize(term.decode::<rustler::Binary>()?.as_slice())
+        }
+        Type::Tuple(types) => {
+            let elements = rustler::types::tuple::get_tuple(term)?;
+
+            if elements.len() != types.len() {
+                return Err(rustler::Error::BadArg);
+            }
+
+            for (r#type, element) in types.iter().zip(elements) {
+                serialize_term(serializer, r#type, element)?;
+            }
+        }
+        Type::List(r#type) => {
+            let elements = term.decode::<Vec<rustler::Term>>()?;
+
+            serializer.serialize(zenoh_ext::VarInt(elements.len()));
+            for element in elements {
+                serialize_term(serializer, r#type, element)?;
+            }
+        }
+        Type::Map(key_type, value_type) => {
+            let map_iter = rustler::MapIterator::new(term).ok_or(rustler::Error::BadArg)?;
+
+            serializer.serialize(zenoh_ext::VarInt(term.map_size()?));
+            for (key, value) in map_iter {
+                serialize_term(serializer, key_type, key)?;
+                serialize_term(serializer, value_type, value)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn deserialize<T: zenoh_ext::Deserialize>(
+    deserializer: &mut zenoh_ext::ZDeserializer,
+) -> rustler::NifResult<T> {
+    deserializer
+        .deserialize::<T>()
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(format!("{:?}", error))))
+}
+
+// WHY: `len` comes from the payload and may be up to `usize::MAX`. Elements which consume
+//      no bytes would never run out of payload, so one would loop `len` times building terms.
+fn deserialize_len(
+    deserializer: &mut zenoh_ext::ZDeserializer,
+    element_consumes_bytes: bool,
+) -> rustler::NifResult<usize> {
+    if !element_consumes_bytes {
+        return Err(rustler::Error::Term(Box::new(
+            "list and map elements must not be empty tuples",
+        )));
+    }
+
+    let zenoh_ext::VarInt(len) = deserialize::<zenoh_ext::VarInt<usize>>(deserializer)?;
+
+    Ok(len)
+}
+
+fn deserialize_term<'a>(
+    env: rustler::Env<'a>,
+    deserializer: &mut zenoh_ext::ZDeserializer,
+    r#type: &Type,
+) -> rustler::NifResult<rustler::Term<'a>> {
+    let term = match r#type {
+        Type::Scalar(Scalar::U8) => deserialize::<u8>(deserializer)?.encode(env),
+        Type::Scalar(Scalar::U16) => deserialize::<u16>(deserializer)?.encode(env),
+        Type::Scalar(Scalar::U32) => deserialize::<u32>(deserializer)?.encode(env),
+        Type::Scalar(Scalar::U64) => deserialize::<u64>(deserializer)?.encode(env),
+        Type::Scalar(Scalar::I8) => deserialize::<i8>(deserializer)?.encode(env),
+        Type::Scalar(Scalar::I16) => deserialize::<i16>(deserializer)?.encode(env),
+        Type::Scalar(Scalar::I32) => deserialize::<i32>(deserializer)?.encode(env),
+        Type::Scalar(Scalar::I64) => deserialize::<i64>(deserializer)?.encode(env),
+        Type::Scalar(Scalar::F32) => (deserialize::<f32>(deserializer)? as f64).encode(env),
+        Type::Scalar(Scalar::F64) => deserialize::<f64>(deserializer)?.encode(env),
+        Type::Scalar(Scalar::Bool) => deserialize::<bool>(deserializer)?.encode(env),
+        Type::Scalar(Scalar::String) => deserialize::<String>(deserializer)?.encode(env),
+        Type::Scalar(Scalar::Bytes) => {
+            let bytes = deserialize::<Vec<u8>>(deserializer)?;
+            let mut owned_binary = rustler::OwnedBinary::new(bytes.len()).unwrap();
+            owned_binary.as_mut_slice().write_all(&bytes).unwrap();
+            owned_binary.release(env).encode(env)
+        }
+        Type::Tuple(types) => {
+            let elements = types
+                .iter()
+                .map(|r#type| deserialize_term(env, deserializer, r#type))
+                .collect::<rustler::NifResult<Vec<rustler::Term>>>()?;
+
+            rustler::types::tuple::make_tuple(env, &elements)
+        }
+        Type::List(r#type) => {
+            let len = deserialize_len(deserializer, r#type.consumes_bytes())?;
+
+            (0..len)
+                .map(|_| deserialize_term(env, deserializer, r#type))
+                .collect::<rustler::NifResult<Vec<rustler::Term>>>()?
+                .encode(env)
+        }
+        Type::Map(key_type, value_type) => {
+            let len = deserialize_len(
+                deserializer,
+                key_type.consumes_bytes() || value_type.consumes_bytes(),
+            )?;
+            // WHY: `len` comes from the payload, so it must not size an allocation,
+            //      a failed one aborts the VM. A truncated payload fails on its first missing entry.
+            let mut keys = Vec::new();
+            let mut values = Vec::new();
+
+            for _ in 0..len {
+                keys.push(deserialize_term(env, deserializer, key_type)?);
+                values.push(deserialize_term(env, deserializer, value_type)?);
+            }
+
+            rustler::Term::map_from_term_arrays(env, &keys, &values)?
+        }
+    };
+
+    Ok(term)
+}
+
+#[rustler::nif]
+fn serialization_serialize<'a>(
+    env: rustler::Env<'a>,
+    term: rustler::Term<'a>,
+    r#type: Type,
+) -> rustler::NifResult<(rustler::Atom, rustler::Binary<'a>)> {
+    let mut serializer = zenoh_ext::ZSerializer::new();
+    serialize_term(&mut serializer, &r#type, term)?;
+
+    let zbytes = serializer.finish();
+    let mut owned_binary = rustler::OwnedBinary::new(zbytes.len()).unwrap();
+
+    owned_binary
+        .as_mut_slice()
+        .write_all(&zbytes.to_bytes())
+        .unwrap();
+
+    Ok((rustler::types::atom::ok(), owned_binary.release(env)))
+}
+
+#[rustler::nif]
+fn serialization_deserialize<'a>(
+    env: rustler::Env<'a>,
+    binary: rustler::Binary<'a>,
+    r#type: Type,
+) -> rustler::NifResult<(rustler::Atom, rustler::Term<'a>)> {
+    let zbytes = zenoh::bytes::ZBytes::from(binary.as_slice());
+    let mut deserializer = zenoh_ext::ZDeserializer::new(&zbytes);
+    let term = deserialize_term(env, &mut deserializer, &r#type)?;
+
+    if !deserializer.done() {
+        return Err(rustler::Error::Term(Box::new(
+            "trailing bytes after deserialization",
+        )));
+    }
+
+    Ok((rustler::types::atom::ok(), term))
+}
@@ -1,14 +1,18 @@
 use core::fmt;
 use std::collections::HashMap;
 use std::ops::Deref;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::LazyLock;
 use std::sync::RwLock;
+use std::sync::Weak;
 use std::time::Duration;
 use std::time::Instant;
 
 use rustler::Encoder;
 use zenoh::Wait;
+use zenoh_ext::AdvancedPublisherBuilderExt;
+use zenoh_ext::AdvancedSubscriberBuilderExt;
 
 use crate::builder::Builder;
 
@@ -29,6 +33,14 @@ pub enum Entity<'a> {
         zenoh::query::Queryable<()>,
         #[allow(dead_code)] rustler::ResourceArc<SessionIdResource>,
     ),
+    AdvancedPublisher(
+        zenoh_ext::AdvancedPublisher<'a>,
+        #[allow(dead_code)] rustler::ResourceArc<SessionIdResource>,
+    ),
+    AdvancedSubscriber(
+        zenoh_ext::AdvancedSubscriber<()>,
+        #[allow(dead_code)] rustler::ResourceArc<SessionIdResource>,
+    ),
 }
 
 impl fmt::Display for Entity<'_> {
@@ -38,13 +50,78 @@ impl fmt::Display for Entity<'_> {
             Entity::Querier(_, _) => write!(f, "Querier"),
             Entity::Subscriber(_, _) => write!(f, "Subscriber"),
             Entity::Queryable(_, _) => write!(f, "Queryable"),
+            Entity::AdvancedPublisher(_, _) => write!(f, "AdvancedPublisher"),
+            Entity::AdvancedSubscriber(_, _) => write!(f, "AdvancedSubscriber"),
+        }
+    }
+}
+
+impl Entity<'_> {
+    fn undeclare(self) -> zenoh::Result<()> {
+        match self {
+            Entity::Publisher(publisher, _) => publisher.undeclare().wait(),
+            Entity::Querier(querier, _) => querier.undeclare().wait(),
+            Entity::Subscriber(subscriber, _) => subscriber.undeclare().wait(),
+            Entity::Queryable(queryable, _) => queryable.undeclare().wait(),
+            Entity::AdvancedPublisher(publisher, _) => publisher.undeclare().wait(),
+            Entity::AdvancedSubscriber(subscriber, _) => subscriber.undeclare().wait(),
         }
     }
 }
 
+#[derive(Clone, Copy, PartialEq, rustler::NifUnitEnum)]
+pub enum EntityKind {
+    Publisher,
+    AdvancedPublisher,
+    Querier,
+    Subscriber,
+    AdvancedSubscriber,
+    LivelinessSubscriber,
+    Queryable,
+}
+
+// Declaration details kept for `session_entities`, which zenoh entities do not expose.
+pub struct EntityMetadata {
+    kind: EntityKind,
+    key_expr: String,
+    pid: Option<rustler::LocalPid>,
+    opts: crate::helper::term::EncodedTerm,
+}
+
+impl EntityMetadata {
+    // NOTE: `opts` are kept with the zenoh defaults applied, see `effective_declare_opts`.
+    pub fn new(
+        session: &zenoh::Session,
+        kind: EntityKind,
+        key_expr: &str,
+        pid: Option<rustler::LocalPid>,
+        opts: rustler::Term,
+    ) -> rustler::NifResult<EntityMetadata> {
+        // WHY: Queriers default to the query timeout of the session config.
+        let queries_default_timeout = session.config().queries_default_timeout_ms();
+        let effective_opts =
+            crate::builder::effective_declare_opts(kind, queries_default_timeout, opts)?;
+
+        Ok(EntityMetadata {
+            kind,
+            key_expr: key_expr.to_string(),
+            pid,
+            opts: crate::helper::term::EncodedTerm::new(effective_opts.encode(opts.get_env())),
+        })
+    }
+}
+
 pub struct Session<'a> {
     inner: zenoh::Session,
+    // NOTE: zenoh::Session does not expose its runtime, so it is kept from `session_open`
+    //       to read the mode and to manage plugins.
+    runtime: zenoh::internal::runtime::Runtime,
     entities: HashMap<zenoh::session::EntityGlobalId, Entity<'a>>,
+    entity_metadata: HashMap<zenoh::session::EntityGlobalId, EntityMetadata>,
+    // NOTE: Weak, so that undeclared or garbage collected connectivity listeners
+    //       are not notified of the session close.
+    connectivity_deliveries:
+        Vec<Weak<crate::helper::delivery::Delivery<crate::connectivity::ConnectivityEvent>>>,
 }
 
 impl<'a> Session<'a> {
@@ -52,10 +129,14 @@ impl<'a> Session<'a> {
         &mut self,
         entity_global_id: zenoh::session::EntityGlobalId,
         entity: Entity<'a>,
+        metadata: EntityMetadata,
     ) -> rustler::NifResult<rustler::Atom> {
         match self.entities.insert(entity_global_id, entity) {
             Some(_entity) => Err(rustler::Error::Term(Box::new("entity already existed"))),
-            None => Ok(rustler::types::atom::ok()),
+            None => {
+                self.entity_metadata.insert(entity_global_id, metadata);
+                Ok(rustler::types::atom::ok())
+            }
         }
     }
 
@@ -72,10 +153,54 @@ impl<'a> Session<'a> {
         &mut self,
         entity_global_id: &zenoh::session::EntityGlobalId,
     ) -> rustler::NifResult<Entity<'a>> {
+        self.entity_metadata.remove(entity_global_id);
         self.entities
             .remove(entity_global_id)
             .ok_or_else(|| rustler::Error::Term(Box::new("entity not found")))
     }
+
+    pub fn insert_connectivity_delivery(
+        &mut self,
+        delivery: &Arc<crate::helper::delivery::Delivery<crate::connectivity::ConnectivityEvent>>,
+    ) {
+        self.connectivity_deliveries
+            .retain(|delivery| delivery.strong_count() > 0);
+        self.connectivity_deliveries.push(Arc::downgrade(delivery));
+    }
+
+    pub fn runtime(&self) -> &zenoh::internal::runtime::Runtime {
+        &self.runtime
+    }
+
+    // WHY: A session initialized on a runtime does not close it, see `session_open`.
+    //      Plugins are stopped first, since closing the runtime does not stop them.
+    //      They are stopped and the runtime is closed even if the session fails to close,
+    //      otherwise its listeners would stay bound with nothing left to close them.
+    fn close_with_runtime(&self) -> zenoh::Result<()> {
+        let session_result = self.inner.close().wait();
+
+        for plugin in self.runtime.plugins_manager().started_plugins_iter_mut() {
+            plugin.stop();
+        }
+
+        let runtime_result = self.runtime.close().wait();
+
+        session_result.and(runtime_result)
+    }
+
+    fn notify_closed(&self, reason: crate::connectivity::SessionCloseReason) {
+        let deliveries = self
+            .connectivity_deliveries
+            .iter()
+            .filter_map(Weak::upgrade);
+
+        for delivery in deliveries {
+            delivery.push(crate::connectivity::ConnectivityEvent::SessionClosed(
+                self.zid(),
+                reason,
+            ));
+        }
+    }
 }
 
 impl Deref for Session<'_> {
@@ -105,13 +230,17 @@ impl<'a> SessionMap<'_> {
         session_map: &SessionMap,
         session_id: zenoh::session::ZenohId,
         session: zenoh::Session,
+        runtime: zenoh::internal::runtime::Runtime,
     ) -> rustler::NifResult<rustler::Atom> {
         let mut map = session_map.write().unwrap();
         match map.insert(
             session_id,
             Arc::new(RwLock::new(Session {
                 inner: session,
+                runtime,
                 entities: HashMap::new(),
+                entity_metadata: HashMap::new(),
+                connectivity_deliveries: Vec::new(),
             })),
         ) {
             Some(_) => Err(rustler::Error::Term(Box::new("session already existed"))),
@@ -165,13 +294,16 @@ impl Drop for SessionIdResource {
         match SessionMap::remove_session(&SESSION_MAP, session_id) {
             Ok(session) => {
                 let session_locked = session.read().unwrap();
-                let message = if session_locked.is_closed() {
-                    "session already closed"
+                if session_locked.is_closed() {
+                    log::debug!("session already closed");
                 } else {
-                    session_locked.close().wait().unwrap();
-                    "session closed by drop"
-                };
-                log::debug!("{}", message)
+                    // NOTE: A drop cannot return the error, so it is only logged.
+                    if let Err(error) = session_locked.close_with_runtime() {
+                        log::warn!("session close by drop failed: {}", error);
+                    }
+                    session_locked.notify_closed(crate::connectivity::SessionCloseReason::Drop);
+                    log::debug!("session closed by drop");
+                }
             }
             Err(_error) => log::debug!("session already removed"),
         };
@@ -182,14 +314,26 @@ impl Drop for SessionIdResource {
 #[module = "Zenohex.Session.Info"]
 pub struct ZenohexSessionInfo {
     zid: String,
+    whatami: crate::config::WhatAmI,
+    locators: Vec<String>,
     routers_zid: Vec<String>,
     peers_zid: Vec<String>,
+    transports: Vec<ZenohexTransport>,
 }
 
-impl From<zenoh::session::SessionInfo> for ZenohexSessionInfo {
-    fn from(value: zenoh::session::SessionInfo) -> Self {
+impl ZenohexSessionInfo {
+    fn new(session: &Session) -> Self {
+        let value = session.info();
+
         let zid = value.zid().wait().to_string();
 
+        let locators = value
+            .locators()
+            .wait()
+            .into_iter()
+            .map(|locator| crate::config::Locator::from(locator).to_string())
+            .collect();
+
         let routers_zid = value
             .routers_zid()
             .wait()
@@ -203,22 +347,129 @@ impl From<zenoh::session::SessionInfo> for ZenohexSessionInfo {
             vec
         });
 
+        let transports = value
+            .transports()
+            .wait()
+            .map(|transport| {
+                let links = value
+                    .links()
+                    .transport(transport.clone())
+                    .wait()
+                    .map(ZenohexLink::from)
+                    .collect();
+
+                ZenohexTransport::new(&transport, links)
+            })
+            .collect();
+
         ZenohexSessionInfo {
             zid,
+            whatami: session.runtime.whatami().into(),
+            locators,
             routers_zid,
             peers_zid,
+            transports,
+        }
+    }
+}
+
+#[derive(Clone, rustler::NifStruct)]
+#[module = "Zenohex.Session.Link"]
+pub struct ZenohexLink {
+    protocol: String,
+    src: String,
+    dst: String,
+    group: Option<String>,
+    mtu: u16,
+    streamed: bool,
+    interfaces: Vec<String>,
+}
+
+impl From<zenoh::session::Link> for ZenohexLink {
+    fn from(link: zenoh::session::Link) -> Self {
+        ZenohexLink {
+            protocol: link.src().protocol().as_str().to_string(),
+            src: crate::config::Locator::from(link.src().clone()).to_string(),
+            dst: crate::config::Locator::from(link.dst().clone()).to_string(),
+            group: link
+                .group()
+                .map(|group| crate::config::Locator::from(group.clone()).to_string()),
+            mtu: link.mtu(),
+            streamed: link.is_streamed(),
+            interfaces: link.interfaces().to_vec(),
+        }
+    }
+}
+
+#[derive(rustler::NifStruct)]
+#[module = "Zenohex.Session.Transport"]
+pub struct ZenohexTransport {
+    zid: String,
+    whatami: crate::config::WhatAmI,
+    qos: bool,
+    multicast: bool,
+    shm: bool,
+    links: Vec<ZenohexLink>,
+}
+
+impl ZenohexTransport {
+    pub fn new(transport: &zenoh::session::Transport, links: Vec<ZenohexLink>) -> Self {
+        ZenohexTransport {
+            zid: transport.zid().to_string(),
+            whatami: transport.whatami().into(),
+            qos: transport.is_qos(),
+            multicast: transport.is_multicast(),
+            shm: transport.is_shm(),
+            links,
         }
     }
 }
 
-pub struct EntityGlobalIdResource(zenoh::session::EntityGlobalId);
+pub struct EntityGlobalIdResource {
+    entity_global_id: zenoh::session::EntityGlobalId,
+    // NOTE: Only pull-mode entities have a receiver.
+    //       It is kept here, instead of in `Session`, so that receiving
+    //       does not need to take the session lock.
+    receiver: Option<rustler::ResourceArc<crate::receiver::ReceiverResource>>,
+    // NOTE: Only the resource returned at declaration owns the entity
+    //       and undeclares it when garbage collected.
+    owner: bool,
+}
 
 #[rustler::resource_impl]
 impl rustler::Resource for EntityGlobalIdResource {}
 
 impl EntityGlobalIdResource {
     pub fn new(entity_global_id: zenoh::session::EntityGlobalId) -> EntityGlobalIdResource {
-        EntityGlobalIdResource(entity_global_id)
+        EntityGlobalIdResource {
+            entity_global_id,
+            receiver: None,
+            owner: true,
+        }
+    }
+
+    // Creates a resource which refers to an already declared entity without owning it.
+    fn borrowed(entity_global_id: zenoh::session::EntityGlobalId) -> EntityGlobalIdResource {
+        EntityGlobalIdResource {
+            entity_global_id,
+            receiver: None,
+            owner: false,
+        }
+    }
+
+    pub fn with_receiver(
+        entity_global_id: zenoh::session::EntityGlobalId,
+        receiver: crate::receiver::ReceiverResource,
+    ) -> EntityGlobalIdResource {
+        EntityGlobalIdResource {
+            entity_global_id,
+            receiver: Some(rustler::ResourceArc::new(receiver)),
+            owner: true,
+        }
+    }
+
+    pub fn receiver(&self) -> Option<rustler::ResourceArc<crate::receiver::ReceiverResource>> {
+        self.receiver.clone()
     }
 }
 
@@ -226,14 +477,18 @@ impl Deref for EntityGlobalIdResource {
     type Target = zenoh::session::EntityGlobalId;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.entity_global_id
     }
 }
 
 impl Drop for EntityGlobalIdResource {
     fn drop(&mut self) {
-        let session_id = &self.0.zid();
-        let entity_global_id = &self.0;
+        if !self.owner {
+            return;
+        }
+
+        let session_id = &self.entity_global_id.zid();
+        let entity_global_id = &self.entity_global_id;
 
         if let Ok(session) = SessionMap::get_session(&SESSION_MAP, session_id) {
             let mut session_locked = session.write().unwrap();
@@ -247,6 +502,23 @@ impl Drop for EntityGlobalIdResource {
     }
 }
 
+fn aggregated_key_exprs(
+    config: &zenoh::Config,
+    key: &str,
+) -> rustler::NifResult<Vec<zenoh::key_expr::OwnedKeyExpr>> {
+    let json = config
+        .get_json(key)
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+    let key_exprs: Vec<String> = serde_json::from_str(&json)
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    key_exprs
+        .iter()
+        .map(|key_expr| zenoh::key_expr::OwnedKeyExpr::from_str(key_expr))
+        .collect::<Result<_, _>>()
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))
+}
+
 #[rustler::nif]
 fn session_open(
     json5_binary: &str,
@@ -254,13 +526,32 @@ fn session_open(
     let config = zenoh::Config::from_json5(json5_binary)
         .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
 
-    let session = zenoh::open(config)
-        .wait()
+    let aggregated_subscribers = aggregated_key_exprs(&config, "aggregation/subscribers")?;
+    let aggregated_publishers = aggregated_key_exprs(&config, "aggregation/publishers")?;
+
+    let plugins_manager = crate::plugins::new_plugins_manager(&config)
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    // WHY: Do what `zenoh::open` does, but keep the runtime to manage its plugins.
+    let (runtime, session) = zenoh::internal::runtime::ZRuntime::Application
+        .block_in_place(async move {
+            let mut runtime = zenoh::internal::runtime::RuntimeBuilder::new(config)
+                .plugins_manager(plugins_manager)
+                .build()
+                .await?;
+            let session = zenoh::session::init(runtime.clone().into())
+                .aggregated_subscribers(aggregated_subscribers)
+                .aggregated_publishers(aggregated_publishers)
+                .await?;
+            runtime.start().await?;
+
+            zenoh::Result::Ok((runtime, session))
+        })
         .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
 
     let session_id = session.zid();
 
-    SessionMap::insert_session(&SESSION_MAP, session_id, session)?;
+    SessionMap::insert_session(&SESSION_MAP, session_id, session, runtime)?;
 
     Ok((
         rustler::types::atom::ok(),
@@ -276,10 +567,11 @@ fn session_close(
     let session = SessionMap::remove_session(&SESSION_MAP, session_id)?;
     let session_locked = session.read().unwrap();
 
-    session_locked
-        .close()
-        .wait()
-        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+    // NOTE: The runtime is closed even if this fails, so listeners are notified either way.
+    let result = session_locked.close_with_runtime();
+    session_locked.notify_closed(crate::connectivity::SessionCloseReason::Close);
+
+    result.map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
 
     Ok(rustler::types::atom::ok())
 }
@@ -287,14 +579,15 @@ fn session_close(
 #[rustler::nif]
 fn session_put(
     session_id_resource: rustler::ResourceArc<SessionIdResource>,
-    key_expr: &str,
-    payload: rustler::Binary,
+    key_expr: crate::keyexpr::KeyExprArg,
+    payload: rustler::Term,
     opts: rustler::Term,
 ) -> rustler::NifResult<rustler::Atom> {
     let session_id = &session_id_resource;
     let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
     let session_locked = session.read().unwrap();
-    let publication_builder = session_locked.put(key_expr, payload.as_slice());
+    let payload = crate::shm::decode_payload(payload)?;
+    let publication_builder = session_locked.put(key_expr, payload);
 
     publication_builder
         .apply_opts(opts)?
@@ -307,7 +600,7 @@ fn session_put(
 #[rustler::nif]
 fn session_delete(
     session_id_resource: rustler::ResourceArc<SessionIdResource>,
-    key_expr: &str,
+    key_expr: crate::keyexpr::KeyExprArg,
     opts: rustler::Term,
 ) -> rustler::NifResult<rustler::Atom> {
     let session_id = &session_id_resource;
@@ -327,7 +620,7 @@ fn session_delete(
 fn session_get<'a>(
     env: rustler::Env<'a>,
     session_id_resource: rustler::ResourceArc<SessionIdResource>,
-    selector: &'a str,
+    selector: crate::selector::SelectorArg,
     timeout: u64,
     opts: rustler::Term,
 ) -> rustler::NifResult<(rustler::Atom, Vec<rustler::Term<'a>>)> {
@@ -338,7 +631,7 @@ fn session_get<'a>(
     //      undeclare or session close can be blocked until timeout.
     let channel_handler = {
         let session_locked = session.read().unwrap();
-        let session_get_builder = session_locked.get(selector);
+        let session_get_builder = session_locked.get(selector.with_opts(opts)?);
 
         session_get_builder
             .apply_opts(opts)?
@@ -372,29 +665,98 @@ fn session_get<'a>(
             }
         };
 
-        let term = match reply.result() {
-            Ok(sample) => crate::sample::ZenohexSample::from(env, sample.clone()).encode(env),
-            Err(reply_error) => {
-                crate::query::ZenohexQueryReplyError::from(env, reply_error.clone()).encode(env)
-            }
-        };
-
-        replies.push(term);
+        replies.push(crate::query::encode_reply(env, reply));
     }
 
     Ok((rustler::types::atom::ok(), replies))
 }
 
+#[rustler::nif]
+fn session_get_async(
+    session_id_resource: rustler::ResourceArc<SessionIdResource>,
+    selector: crate::selector::SelectorArg,
+    // WHY: Pass `pid` instead of using `env.pid()`
+    //      so the user can specify any receiver process
+    pid: rustler::LocalPid,
+    reference: rustler::Term,
+    opts: rustler::Term,
+) -> rustler::NifResult<rustler::Atom> {
+    let session_id = &session_id_resource;
+    let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
+    let session_locked = session.read().unwrap();
+    let (delivery, get_started) = crate::query::get_async_delivery(pid, reference, opts)?;
+
+    session_locked
+        .get(selector.with_opts(opts)?)
+        .apply_opts(opts)?
+        .callback(move |reply| delivery.push(reply))
+        .wait()
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    get_started.started();
+
+    Ok(rustler::types::atom::ok())
+}
+
+#[rustler::nif]
+fn session_get_pull(
+    session_id_resource: rustler::ResourceArc<SessionIdResource>,
+    selector: crate::selector::SelectorArg,
+    handler: crate::receiver::Handler,
+    opts: rustler::Term,
+) -> rustler::NifResult<(
+    rustler::Atom,
+    rustler::ResourceArc<crate::receiver::ReceiverResource>,
+)> {
+    let session_id = &session_id_resource;
+    let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
+    let session_locked = session.read().unwrap();
+    let (callback, receiver) = handler.into_handler()?;
+
+    session_locked
+        .get(selector.with_opts(opts)?)
+        .apply_opts(opts)?
+        .callback(move |reply| callback.call(reply))
+        .wait()
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    Ok((
+        rustler::types::atom::ok(),
+        rustler::ResourceArc::new(crate::receiver::ReceiverResource::Reply(receiver)),
+    ))
+}
+
 #[rustler::nif]
 fn session_new_timestamp(
     session_id_resource: rustler::ResourceArc<SessionIdResource>,
-) -> rustler::NifResult<(rustler::Atom, String)> {
+) -> rustler::NifResult<(rustler::Atom, crate::timestamp::ZenohexTimestamp)> {
     let session_id = &session_id_resource;
     let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
     let session_locked = session.read().unwrap();
-    let timestamp = session_locked.new_timestamp().to_string_rfc3339_lossy();
+    let timestamp = session_locked.new_timestamp();
 
-    Ok((rustler::types::atom::ok(), timestamp))
+    Ok((rustler::types::atom::ok(), (&timestamp).into()))
+}
+
+#[rustler::nif]
+fn session_update_with_timestamp(
+    session_id_resource: rustler::ResourceArc<SessionIdResource>,
+    timestamp: crate::timestamp::ZenohexTimestamp,
+) -> rustler::NifResult<rustler::Atom> {
+    let session_id = &session_id_resource;
+    let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
+    let session_locked = session.read().unwrap();
+    let timestamp = zenoh::time::Timestamp::try_from(timestamp)?;
+
+    // NOTE: The HLC is absent only when timestamping is disabled in the config.
+    let hlc = session_locked
+        .hlc()
+        .ok_or_else(|| rustler::Error::Term(Box::new("session has no HLC")))?;
+
+    hlc.update_with_timestamp(&timestamp)
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    Ok(rustler::types::atom::ok())
 }
 
 #[rustler::nif]
@@ -404,20 +766,283 @@ fn session_info(
     let session_id = &session_id_resource;
     let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
     let session_locked = session.read().unwrap();
-    let zenohex_session_info = session_locked.info().into();
+    let zenohex_session_info = ZenohexSessionInfo::new(&session_locked);
 
     Ok((rustler::types::atom::ok(), zenohex_session_info))
 }
 
 #[rustler::nif]
-fn session_declare_publisher(
+fn session_config(
+    session_id_resource: rustler::ResourceArc<SessionIdResource>,
+) -> rustler::NifResult<(rustler::Atom, String)> {
+    let session_id = &session_id_resource;
+    let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
+    let session_locked = session.read().unwrap();
+    let config = session_locked.runtime().config().lock().to_string();
+
+    Ok((rustler::types::atom::ok(), config))
+}
+
+#[rustler::nif]
+fn session_config_get_json(
+    session_id_resource: rustler::ResourceArc<SessionIdResource>,
+    key: &str,
+) -> rustler::NifResult<(rustler::Atom, String)> {
+    let session_id = &session_id_resource;
+    let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
+    let session_locked = session.read().unwrap();
+    let value = session_locked
+        .runtime()
+        .config()
+        .lock()
+        .get_json(key)
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    Ok((rustler::types::atom::ok(), value))
+}
+
+// NOTE: The runtime validates the key, zenoh only applies changes under `plugins/` live
+//       and rejects the other keys, whose values are read once when the session opens.
+//       Endpoints are connected live by `session_connect` instead.
+#[rustler::nif]
+fn session_config_insert_json5(
+    session_id_resource: rustler::ResourceArc<SessionIdResource>,
+    key: &str,
+    value: &str,
+) -> rustler::NifResult<rustler::Atom> {
+    let session_id = &session_id_resource;
+    let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
+    let session_locked = session.read().unwrap();
+
+    session_locked
+        .runtime()
+        .config()
+        .insert_json5(key, value)
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    Ok(rustler::types::atom::ok())
+}
+
+// WHY: `Runtime::connect_peer` needs the zid of the node behind the endpoint,
+//      so it is read from a client session connected to the endpoint first.
+//      The client shares the transport config of the session, e.g. its TLS settings.
+fn resolve_endpoint_zid(
+    runtime: &zenoh::internal::runtime::Runtime,
+    endpoint: &zenoh::config::EndPoint,
+) -> zenoh::Result<zenoh::session::ZenohId> {
+    let transport = runtime
+        .config()
+        .lock()
+        .get_json("transport")
+        .map_err(|error| error.to_string())?;
+    let connect_endpoints = serde_json::json!([endpoint.to_string()]).to_string();
+
+    let mut config = zenoh::Config::default();
+    config.insert_json5("mode", r#""client""#)?;
+    config.insert_json5("connect/endpoints", &connect_endpoints)?;
+    config.insert_json5("listen/endpoints", "[]")?;
+    config.insert_json5("scouting/multicast/enabled", "false")?;
+    config.insert_json5("transport", &transport)?;
+
+    let client = zenoh::open(config).wait()?;
+    let zid = client
+        .info()
+        .routers_zid()
+        .wait()
+        .chain(client.info().peers_zid().wait())
+        .next();
+    client.close().wait()?;
+
+    zid.ok_or_else(|| format!("no zenoh node replied on {endpoint}").into())
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn session_connect(
+    session_id_resource: rustler::ResourceArc<SessionIdResource>,
+    endpoint: &str,
+) -> rustler::NifResult<rustler::Atom> {
+    let session_id = &session_id_resource;
+    let endpoint = zenoh::config::EndPoint::from_str(endpoint)
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+    // WHY: Clone the runtime, so that the session lock is not held while connecting.
+    let runtime = {
+        let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
+        let session_locked = session.read().unwrap();
+        session_locked.runtime().clone()
+    };
+
+    let zid = resolve_endpoint_zid(&runtime, &endpoint)
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    if zid == runtime.zid() {
+        return Err(rustler::Error::Term(Box::new(
+            "endpoint is one of the session itself",
+        )));
+    }
+
+    let connected = zenoh::internal::runtime::ZRuntime::Application
+        .block_in_place(runtime.connect_peer(&zid.into(), &[endpoint.to_locator()]));
+
+    if connected {
+        Ok(rustler::types::atom::ok())
+    } else {
+        Err(rustler::Error::Term(Box::new(format!(
+            "unable to connect to {endpoint}"
+        ))))
+    }
+}
+
+#[derive(rustler::NifStruct)]
+#[module = "Zenohex.Session.Entity"]
+pub struct ZenohexEntity<'a> {
+    id: rustler::ResourceArc<EntityGlobalIdResource>,
+    kind: EntityKind,
+    key_expr: String,
+    pid: Option<rustler::LocalPid>,
+    opts: rustler::Term<'a>,
+}
+
+#[rustler::nif]
+fn session_entities<'a>(
+    env: rustler::Env<'a>,
+    session_id_resource: rustler::ResourceArc<SessionIdResource>,
+) -> rustler::NifResult<(rustler::Atom, Vec<ZenohexEntity<'a>>)> {
+    let session_id = &session_id_resource;
+    let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
+    let session_locked = session.read().unwrap();
+
+    let entities = session_locked
+        .entity_metadata
+        .iter()
+        .map(|(entity_global_id, metadata)| ZenohexEntity {
+            id: rustler::ResourceArc::new(EntityGlobalIdResource::borrowed(*entity_global_id)),
+            kind: metadata.kind,
+            key_expr: metadata.key_expr.clone(),
+            pid: metadata.pid,
+            opts: metadata.opts.decode(env),
+        })
+        .collect();
+
+    Ok((rustler::types::atom::ok(), entities))
+}
+
+fn decode_entity_kinds(term: rustler::Term) -> rustler::NifResult<Vec<EntityKind>> {
+    match term.decode::<EntityKind>() {
+        Ok(kind) => Ok(vec![kind]),
+        Err(_) => term.decode::<Vec<EntityKind>>(),
+    }
+}
+
+#[rustler::nif]
+fn session_undeclare_all(
+    session_id_resource: rustler::ResourceArc<SessionIdResource>,
+    opts: rustler::Term,
+) -> rustler::NifResult<(rustler::Atom, usize)> {
+    let session_id = &session_id_resource;
+    let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
+    let mut session_locked = session.write().unwrap();
+
+    let mut kinds: Option<Vec<EntityKind>> = None;
+    let mut key_expr: Option<zenoh::key_expr::KeyExpr> = None;
+
+    let opts_iter: rustler::ListIterator = opts.decode()?;
+    for opt in opts_iter {
+        let (k, v): (rustler::Atom, rustler::Term) = opt.decode()?;
+        match k {
+            k if k == crate::atoms::kind() => kinds = Some(decode_entity_kinds(v)?),
+            k if k == crate::atoms::key_expr() => {
+                key_expr = Some(v.decode::<crate::keyexpr::KeyExprArg>()?.into());
+            }
+            _ => {}
+        }
+    }
+
+    let entity_global_ids: Vec<zenoh::session::EntityGlobalId> = session_locked
+        .entity_metadata
+        .iter()
+        .filter(|(_, metadata)| {
+            kinds
+                .as_ref()
+                .is_none_or(|kinds| kinds.contains(&metadata.kind))
+        })
+        .filter(|(_, metadata)| {
+            // NOTE: Canonize the stored key expression, since an entity may have been
+            //       declared with a key expression zenoh accepts but does not keep as is.
+            key_expr.as_ref().is_none_or(|pattern| {
+                zenoh::key_expr::KeyExpr::autocanonize(metadata.key_expr.clone())
+                    .is_ok_and(|key_expr| pattern.includes(&key_expr))
+            })
+        })
+        .map(|(entity_global_id, _)| *entity_global_id)
+        .collect();
+
+    // NOTE: Remove every matching entity under the write lock first,
+    //       so no other NIF observes a partially torn down group.
+    let entities: Vec<Entity> = entity_global_ids
+        .iter()
+        .map(|entity_global_id| session_locked.remove_entity(entity_global_id))
+        .collect::<rustler::NifResult<_>>()?;
+
+    // WHY: Undeclaring waits on zenoh, which may call back into NIFs taking this lock.
+    drop(session_locked);
+
+    let count = entities.len();
+    let mut first_error = None;
+
+    for entity in entities {
+        if let Err(error) = entity.undeclare() {
+            first_error.get_or_insert(error);
+        }
+    }
+
+    match first_error {
+        Some(error) => Err(rustler::Error::Term(crate::zenoh_error!(error))),
+        None => Ok((rustler::types::atom::ok(), count)),
+    }
+}
+
+#[rustler::nif]
+fn session_declare_keyexpr(
     session_id_resource: rustler::ResourceArc<SessionIdResource>,
     key_expr: String,
+) -> rustler::NifResult<(
+    rustler::Atom,
+    rustler::ResourceArc<crate::keyexpr::KeyExprResource>,
+)> {
+    let session_id = &session_id_resource;
+    let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
+    let session_locked = session.read().unwrap();
+
+    let declared = session_locked
+        .declare_keyexpr(key_expr)
+        .wait()
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    Ok((
+        rustler::types::atom::ok(),
+        rustler::ResourceArc::new(crate::keyexpr::KeyExprResource::new(
+            session_id_resource.clone(),
+            declared,
+        )),
+    ))
+}
+
+#[rustler::nif]
+fn session_declare_publisher(
+    session_id_resource: rustler::ResourceArc<SessionIdResource>,
+    key_expr: crate::keyexpr::KeyExprArg,
     opts: rustler::Term,
 ) -> rustler::NifResult<(rustler::Atom, rustler::ResourceArc<EntityGlobalIdResource>)> {
     let session_id = &session_id_resource;
     let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
     let mut session_locked = session.write().unwrap();
+    let metadata = EntityMetadata::new(
+        &session_locked,
+        EntityKind::Publisher,
+        key_expr.as_str(),
+        None,
+        opts,
+    )?;
 
     let publisher_builder = session_locked.declare_publisher(key_expr);
 
@@ -430,6 +1055,49 @@ fn session_declare_publisher(
     session_locked.insert_entity(
         publisher_id,
         Entity::Publisher(publisher, session_id_resource),
+        metadata,
+    )?;
+
+    Ok((
+        rustler::types::atom::ok(),
+        rustler::ResourceArc::new(EntityGlobalIdResource::new(publisher_id)),
+    ))
+}
+
+#[rustler::nif]
+fn session_declare_advanced_publisher(
+    session_id_resource: rustler::ResourceArc<SessionIdResource>,
+    key_expr: crate::keyexpr::KeyExprArg,
+    opts: rustler::Term,
+) -> rustler::NifResult<(rustler::Atom, rustler::ResourceArc<EntityGlobalIdResource>)> {
+    let session_id = &session_id_resource;
+    let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
+    let mut session_locked = session.write().unwrap();
+    let metadata = EntityMetadata::new(
+        &session_locked,
+        EntityKind::AdvancedPublisher,
+        key_expr.as_str(),
+        None,
+        opts,
+    )?;
+
+    // NOTE: Plain publisher options and advanced options share the same keyword list.
+    //       Each builder ignores the options it does not know.
+    let publisher_builder = session_locked
+        .declare_publisher(key_expr)
+        .apply_opts(opts)?
+        .advanced();
+
+    let publisher = publisher_builder
+        .apply_opts(opts)?
+        .wait()
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    let publisher_id = publisher.id();
+    session_locked.insert_entity(
+        publisher_id,
+        Entity::AdvancedPublisher(publisher, session_id_resource),
+        metadata,
     )?;
 
     Ok((
@@ -441,12 +1109,19 @@ fn session_declare_publisher(
 #[rustler::nif]
 fn session_declare_querier(
     session_id_resource: rustler::ResourceArc<SessionIdResource>,
-    key_expr: String,
+    key_expr: crate::keyexpr::KeyExprArg,
     opts: rustler::Term,
 ) -> rustler::NifResult<(rustler::Atom, rustler::ResourceArc<EntityGlobalIdResource>)> {
     let session_id = &session_id_resource;
     let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
     let mut session_locked = session.write().unwrap();
+    let metadata = EntityMetadata::new(
+        &session_locked,
+        EntityKind::Querier,
+        key_expr.as_str(),
+        None,
+        opts,
+    )?;
 
     let querier_builder = session_locked.declare_querier(key_expr);
 
@@ -456,7 +1131,11 @@ fn session_declare_querier(
         .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
 
     let querier_id = querier.id();
-    session_locked.insert_entity(querier_id, Entity::Querier(querier, session_id_resource))?;
+    session_locked.insert_entity(
+        querier_id,
+        Entity::Querier(querier, session_id_resource),
+        metadata,
+    )?;
 
     Ok((
         rustler::types::atom::ok(),
@@ -467,7 +1146,7 @@ fn session_declare_querier(
 #[rustler::nif]
 fn session_declare_subscriber(
     session_id_resource: rustler::ResourceArc<SessionIdResource>,
-    key_expr: String,
+    key_expr: crate::keyexpr::KeyExprArg,
     // WHY: Pass `pid` instead of using `env.pid()`
     //      so the user can specify any receiver process
     pid: rustler::LocalPid,
@@ -476,21 +1155,23 @@ fn session_declare_subscriber(
     let session_id = &session_id_resource;
     let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
     let mut session_locked = session.write().unwrap();
+    let metadata = EntityMetadata::new(
+        &session_locked,
+        EntityKind::Subscriber,
+        key_expr.as_str(),
+        Some(pid),
+        opts,
+    )?;
 
     let subscriber_buidler = session_locked.declare_subscriber(key_expr);
 
+    let delivery = crate::helper::delivery::Delivery::spawn(pid, |env, sample| {
+        crate::sample::ZenohexSample::from(env, sample).encode(env)
+    });
+
     let subscriber = subscriber_buidler
         .apply_opts(opts)?
-        .callback(move |sample| {
-            // WHY: Spawn a thread inside this callback.
-            //      If we don't spawn a thread, a panic will occur.
-            //      See: https://docs.rs/rustler/latest/rustler/env/struct.OwnedEnv.html#panics
-            std::thread::spawn(move || {
-                let _ = rustler::OwnedEnv::new().run(|env: rustler::Env| {
-                    env.send(&pid, crate::sample::ZenohexSample::from(env, sample))
-                });
-            });
-        })
+        .callback(move |sample| delivery.push(sample))
         .wait()
         .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
 
@@ -498,6 +1179,70 @@ fn session_declare_subscriber(
     session_locked.insert_entity(
         subscriber_id,
         Entity::Subscriber(subscriber, session_id_resource),
+        metadata,
+    )?;
+
+    Ok((
+        rustler::types::atom::ok(),
+        rustler::ResourceArc::new(EntityGlobalIdResource::new(subscriber_id)),
+    ))
+}
+
+#[rustler::nif]
+fn session_declare_advanced_subscriber(
+    session_id_resource: rustler::ResourceArc<SessionIdResource>,
+    key_expr: crate::keyexpr::KeyExprArg,
+    // WHY: Pass `pid` instead of using `env.pid()`
+    //      so the user can specify any receiver process
+    pid: rustler::LocalPid,
+    opts: rustler::Term,
+) -> rustler::NifResult<(rustler::Atom, rustler::ResourceArc<EntityGlobalIdResource>)> {
+    let session_id = &session_id_resource;
+    let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
+    let mut session_locked = session.write().unwrap();
+    let metadata = EntityMetadata::new(
+        &session_locked,
+        EntityKind::AdvancedSubscriber,
+        key_expr.as_str(),
+        Some(pid),
+        opts,
+    )?;
+
+    // NOTE: Plain subscriber options and advanced options share the same keyword list.
+    //       Each builder ignores the options it does not know.
+    let subscriber_builder = session_locked
+        .declare_subscriber(key_expr)
+        .apply_opts(opts)?
+        .advanced();
+
+    let delivery = crate::helper::delivery::Delivery::spawn(pid, |env, sample| {
+        crate::sample::ZenohexSample::from(env, sample).encode(env)
+    });
+
+    let subscriber = subscriber_builder
+        .apply_opts(opts)?
+        .callback(move |sample| delivery.push(sample))
+        .wait()
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    let miss_delivery = crate::helper::delivery::Delivery::spawn(pid, |env, miss| {
+        crate::sample::ZenohexSampleMiss::from(miss).encode(env)
+    });
+
+    // NOTE: A background listener lives as long as the subscriber,
+    //       so it does not need to be stored in the entity.
+    subscriber
+        .sample_miss_listener()
+        .callback(move |miss| miss_delivery.push(miss))
+        .background()
+        .wait()
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    let subscriber_id = subscriber.id();
+    session_locked.insert_entity(
+        subscriber_id,
+        Entity::AdvancedSubscriber(subscriber, session_id_resource),
+        metadata,
     )?;
 
     Ok((
@@ -509,7 +1254,7 @@ fn session_declare_subscriber(
 #[rustler::nif]
 fn session_declare_queryable(
     session_id_resource: rustler::ResourceArc<SessionIdResource>,
-    key_expr: String,
+    key_expr: crate::keyexpr::KeyExprArg,
     // WHY: Pass `pid` instead of using `env.pid()`
     //      so the user can specify any receiver process
     pid: rustler::LocalPid,
@@ -518,21 +1263,23 @@ fn session_declare_queryable(
     let session_id = &session_id_resource;
     let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
     let mut session_locked = session.write().unwrap();
+    let metadata = EntityMetadata::new(
+        &session_locked,
+        EntityKind::Queryable,
+        key_expr.as_str(),
+        Some(pid),
+        opts,
+    )?;
 
     let queryable_builder = session_locked.declare_queryable(key_expr);
 
+    let delivery = crate::helper::delivery::Delivery::spawn(pid, |env, query| {
+        crate::query::ZenohexQuery::from(env, query).encode(env)
+    });
+
     let queryable = queryable_builder
         .apply_opts(opts)?
-        .callback(move |query| {
-            // WHY: Spawn a thread inside this callback.
-            //      If we don't spawn a thread, a panic will occur.
-            //      See: https://docs.rs/rustler/latest/rustler/env/struct.OwnedEnv.html#panics
-            std::thread::spawn(move || {
-                let _ = rustler::OwnedEnv::new().run(|env: rustler::Env| {
-                    env.send(&pid, crate::query::ZenohexQuery::from(env, query))
-                });
-            });
-        })
+        .callback(move |query| delivery.push(query))
         .wait()
         .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
 
@@ -540,6 +1287,7 @@ fn session_declare_queryable(
     session_locked.insert_entity(
         queryable_id,
         Entity::Queryable(queryable, session_id_resource),
+        metadata,
     )?;
 
     Ok((
@@ -547,3 +1295,89 @@ fn session_declare_queryable(
         rustler::ResourceArc::new(EntityGlobalIdResource::new(queryable_id)),
     ))
 }
+
+#[rustler::nif]
+fn session_declare_pull_subscriber(
+    session_id_resource: rustler::ResourceArc<SessionIdResource>,
+    key_expr: crate::keyexpr::KeyExprArg,
+    handler: crate::receiver::Handler,
+    opts: rustler::Term,
+) -> rustler::NifResult<(rustler::Atom, rustler::ResourceArc<EntityGlobalIdResource>)> {
+    let session_id = &session_id_resource;
+    let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
+    let mut session_locked = session.write().unwrap();
+    let metadata = EntityMetadata::new(
+        &session_locked,
+        EntityKind::Subscriber,
+        key_expr.as_str(),
+        None,
+        opts,
+    )?;
+
+    let subscriber_buidler = session_locked.declare_subscriber(key_expr);
+    let (callback, receiver) = handler.into_handler()?;
+
+    let subscriber = subscriber_buidler
+        .apply_opts(opts)?
+        .callback(move |sample| callback.call(sample))
+        .wait()
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    let subscriber_id = subscriber.id();
+    session_locked.insert_entity(
+        subscriber_id,
+        Entity::Subscriber(subscriber, session_id_resource),
+        metadata,
+    )?;
+
+    Ok((
+        rustler::types::atom::ok(),
+        rustler::ResourceArc::new(EntityGlobalIdResource::with_receiver(
+            subscriber_id,
+            crate::receiver::ReceiverResource::Sample(receiver),
+        )),
+    ))
+}
+
+#[rustler::nif]
+fn session_declare_pull_queryable(
+    session_id_resource: rustler::ResourceArc<SessionIdResource>,
+    key_expr: crate::keyexpr::KeyExprArg,
+    handler: crate::receiver::Handler,
+    opts: rustler::Term,
+) -> rustler::NifResult<(rustler::Atom, rustler::ResourceArc<EntityGlobalIdResource>)> {
+    let session_id = &session_id_resource;
+    let session = SessionMap::get_session(&SESSION_MAP, session_id)?;
+    let mut session_locked = session.write().unwrap();
+    let metadata = EntityMetadata::new(
+        &session_locked,
+        EntityKind::Queryable,
+        key_expr.as_str(),
+        None,
+        opts,
+    )?;
+
+    let queryable_builder = session_locked.declare_queryable(key_expr);
+    let (callback, receiver) = handler.into_handler()?;
+
+    let queryable = queryable_builder
+        .apply_opts(opts)?
+        .callback(move |query| callback.call(query))
+        .wait()
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    let queryable_id = queryable.id();
+    session_locked.insert_entity(
+        queryable_id,
+        Entity::Queryable(queryable, session_id_resource),
+        metadata,
+    )?;
+
+    Ok((
+        rustler::types::atom::ok(),
+        rustler::ResourceArc::new(EntityGlobalIdResource::with_receiver(
+            queryable_id,
+            crate::receiver::ReceiverResource::Query(receiver),
+        )),
+    ))
+}
@@ -0,0 +1,147 @@
+use std::ops::Deref;
+use std::sync::Mutex;
+
+use zenoh::Wait;
+
+pub struct ShmProviderResource(zenoh::shm::ShmProvider<zenoh::shm::PosixShmProviderBackend>);
+
+#[rustler::resource_impl]
+impl rustler::Resource for ShmProviderResource {}
+
+impl Deref for ShmProviderResource {
+    type Target = zenoh::shm::ShmProvider<zenoh::shm::PosixShmProviderBackend>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+// NOTE: The buffer is taken out of the resource when it is published,
+//       because zenoh takes ownership of the SHM buffer on put.
+pub struct ShmBufferResource(Mutex<Option<zenoh::shm::ZShmMut>>);
+
+#[rustler::resource_impl]
+impl rustler::Resource for ShmBufferResource {}
+
+impl Deref for ShmBufferResource {
+    type Target = Mutex<Option<zenoh::shm::ZShmMut>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl ShmBufferResource {
+    fn new(buffer: zenoh::shm::ZShmMut) -> ShmBufferResource {
+        ShmBufferResource(Mutex::new(Some(buffer)))
+    }
+}
+
+// Decodes a payload given either as a binary or as an SHM buffer.
+// Binaries are copied, while SHM buffers are moved into the payload without copying.
+pub fn decode_payload(term: rustler::Term) -> rustler::NifResult<zenoh::bytes::ZBytes> {
+    if let Ok(binary) = term.decode::<rustler::Binary>() {
+        return Ok(zenoh::bytes::ZBytes::from(binary.as_slice()));
+    }
+
+    let shm_buffer_resource = term.decode::<rustler::ResourceArc<ShmBufferResource>>()?;
+    let mut buffer_option = shm_buffer_resource.lock().unwrap();
+
+    match buffer_option.take() {
+        Some(buffer) => Ok(zenoh::bytes::ZBytes::from(buffer)),
+        None => Err(rustler::Error::Term(Box::new(
+            "shm buffer already published",
+        ))),
+    }
+}
+
+#[rustler::nif]
+fn shm_provider_new(
+    size: usize,
+    // NOTE: Alignment is given as a power of two, e.g. 3 means 8 bytes alignment.
+    alignment: u32,
+) -> rustler::NifResult<(rustler::Atom, rustler::ResourceArc<ShmProviderResource>)> {
+    let alignment = zenoh::shm::AllocAlignment::new(alignment)
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(format!("{:?}", error))))?;
+
+    let layout = zenoh::shm::MemoryLayout::new(size, alignment)
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(format!("{:?}", error))))?;
+
+    let backend = zenoh::shm::PosixShmProviderBackend::builder(&layout)
+        .wait()
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(format!("{:?}", error))))?;
+
+    let provider = zenoh::shm::ShmProviderBuilder::backend(backend).wait();
+
+    Ok((
+        rustler::types::atom::ok(),
+        rustler::ResourceArc::new(ShmProviderResource(provider)),
+    ))
+}
+
+#[rustler::nif]
+fn shm_provider_alloc(
+    shm_provider_resource: rustler::ResourceArc<ShmProviderResource>,
+    size: usize,
+) -> rustler::NifResult<(rustler::Atom, rustler::ResourceArc<ShmBufferResource>)> {
+    // WHY: Use `GarbageCollect` instead of `BlockOn`.
+    //      Blocking until memory is freed would stall the BEAM scheduler,
+    //      so return an error and let the caller decide when to retry.
+    let buffer = shm_provider_resource
+        .alloc(size)
+        .with_policy::<zenoh::shm::GarbageCollect>()
+        .wait()
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(format!("{:?}", error))))?;
+
+    Ok((
+        rustler::types::atom::ok(),
+        rustler::ResourceArc::new(ShmBufferResource::new(buffer)),
+    ))
+}
+
+#[rustler::nif]
+fn shm_buffer_write(
+    shm_buffer_resource: rustler::ResourceArc<ShmBufferResource>,
+    offset: usize,
+    binary: rustler::Binary,
+) -> rustler::NifResult<rustler::Atom> {
+    let mut buffer_option = shm_buffer_resource.lock().unwrap();
+
+    let Some(buffer) = buffer_option.as_mut() else {
+        return Err(rustler::Error::Term(Box::new(
+            "shm buffer already published",
+        )));
+    };
+
+    let end = offset
+        .checked_add(binary.len())
+        .filter(|end| *end <= buffer.len())
+        .ok_or_else(|| {
+            rustler::Error::RaiseTerm(Box::new(crate::helper::exception::ArgumentError {
+                message: format!(
+                    "cannot write {} bytes at offset {} into shm buffer of {} bytes",
+                    binary.len(),
+                    offset,
+                    buffer.len()
+                ),
+            }))
+        })?;
+
+    buffer[offset..end].copy_from_slice(binary.as_slice());
+
+    Ok(rustler::types::atom::ok())
+}
+
+#[rustler::nif]
+fn shm_buffer_len(
+    shm_buffer_resource: rustler::ResourceArc<ShmBufferResource>,
+) -> rustler::NifResult<usize> {
+    let buffer_option = shm_buffer_resource.lock().unwrap();
+
+    match buffer_option.as_ref() {
+        Some(buffer) => Ok(buffer.len()),
+        None => Err(rustler::Error::Term(Box::new(
+            "shm buffer already published",
+        ))),
+    }
+}
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use zenoh::Wait;
+
+use crate::builder::Builder;
+use crate::helper::sample_store::SampleStore;
+
+type StorageEntities = (zenoh::pubsub::Subscriber<()>, zenoh::query::Queryable<()>);
+
+struct StorageResource {
+    entities: Mutex<Option<StorageEntities>>,
+    store: Arc<SampleStore>,
+}
+
+#[rustler::resource_impl]
+impl rustler::Resource for StorageResource {}
+
+impl Drop for StorageResource {
+    fn drop(&mut self) {
+        let mut entities_option = self.entities.lock().unwrap();
+        match entities_option.take() {
+            Some((subscriber, queryable)) => {
+                if let Err(error) = undeclare(subscriber, queryable) {
+                    log::debug!("storage drop undeclare failed: {}", error);
+                }
+            }
+            None => log::debug!("storage already undeclared"),
+        }
+    }
+}
+
+fn undeclare(
+    subscriber: zenoh::pubsub::Subscriber<()>,
+    queryable: zenoh::query::Queryable<()>,
+) -> zenoh::Result<()> {
+    queryable.undeclare().wait()?;
+    subscriber.undeclare().wait()
+}
+
+#[rustler::nif]
+fn storage_declare(
+    session_id_resource: rustler::ResourceArc<crate::session::SessionIdResource>,
+    key_expr: crate::keyexpr::KeyExprArg,
+    opts: rustler::Term,
+) -> rustler::NifResult<(rustler::Atom, rustler::ResourceArc<StorageResource>)> {
+    let session_id = &session_id_resource;
+    let session =
+        crate::session::SessionMap::get_session(&crate::session::SESSION_MAP, session_id)?;
+    let session_locked = session.read().unwrap();
+
+    let store = Arc::new(SampleStore::from_opts(opts)?);
+
+    // WHY: Both callbacks run on zenoh threads and never reach the BEAM,
+    //      which is the point of this entity compared to a queryable answered from Elixir.
+    let subscriber_store = store.clone();
+    let subscriber = session_locked
+        .declare_subscriber(key_expr.clone())
+        .apply_opts(opts)?
+        .callback(move |sample| subscriber_store.insert(sample))
+        .wait()
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    let queryable_store = store.clone();
+    let queryable = session_locked
+        .declare_queryable(key_expr)
+        .apply_opts(opts)?
+        .callback(move |query| {
+            for sample in queryable_store.intersecting(query.key_expr()) {
+                if let Err(error) = query.reply_sample(sample).wait() {
+                    log::error!("storage reply failed: {}", error);
+                }
+            }
+        })
+        .wait()
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+    Ok((
+        rustler::types::atom::ok(),
+        rustler::ResourceArc::new(StorageResource {
+            entities: Mutex::new(Some((subscriber, queryable))),
+            store,
+        }),
+    ))
+}
+
+#[rustler::nif]
+fn storage_undeclare(
+    storage_resource: rustler::ResourceArc<StorageResource>,
+) -> rustler::NifResult<rustler::Atom> {
+    let mut entities_option = storage_resource.entities.lock().unwrap();
+
+    match entities_option.take() {
+        Some((subscriber, queryable)) => {
+            undeclare(subscriber, queryable)
+                .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+
+            Ok(rustler::types::atom::ok())
+        }
+        None => Err(rustler::Error::Term(Box::new("already undeclared"))),
+    }
+}
+
+#[rustler::nif]
+fn storage_get<'a>(
+    env: rustler::Env<'a>,
+    storage_resource: rustler::ResourceArc<StorageResource>,
+    key_expr: &str,
+) -> rustler::NifResult<(rustler::Atom, Vec<crate::sample::ZenohexSample<'a>>)> {
+    let samples = storage_resource
+        .store
+        .intersecting(crate::keyexpr::new(key_expr)?)
+        .into_iter()
+        .map(|sample| crate::sample::ZenohexSample::from(env, sample))
+        .collect();
+
+    Ok((rustler::types::atom::ok(), samples))
+}
+
+#[rustler::nif]
+fn storage_keys(
+    storage_resource: rustler::ResourceArc<StorageResource>,
+) -> rustler::NifResult<(rustler::Atom, Vec<String>)> {
+    Ok((rustler::types::atom::ok(), storage_resource.store.keys()))
+}
+
+#[rustler::nif]
+fn storage_purge(
+    storage_resource: rustler::ResourceArc<StorageResource>,
+    key_expr: &str,
+) -> rustler::NifResult<(rustler::Atom, usize)> {
+    let purged = storage_resource.store.purge(crate::keyexpr::new(key_expr)?);
+
+    Ok((rustler::types::atom::ok(), purged))
+}
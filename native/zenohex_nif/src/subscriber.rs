@@ -13,7 +13,7 @@ fn subscriber_undeclare(
 
     let is_subscriber = matches!(
         session_locked.get_entity(entity_global_id)?,
-        crate::session::Entity::Subscriber(_, _)
+        crate::session::Entity::Subscriber(_, _) | crate::session::Entity::AdvancedSubscriber(_, _)
     );
 
     if !is_subscriber {
@@ -22,15 +22,12 @@ fn subscriber_undeclare(
         )));
     }
 
-    let entity = session_locked.remove_entity(entity_global_id)?;
-    let crate::session::Entity::Subscriber(subscriber, _) = entity else {
-        unreachable!("entity kind changed after subscriber check")
-    };
-
-    subscriber
-        .undeclare()
-        .wait()
-        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
+    match session_locked.remove_entity(entity_global_id)? {
+        crate::session::Entity::Subscriber(subscriber, _) => subscriber.undeclare().wait(),
+        crate::session::Entity::AdvancedSubscriber(subscriber, _) => subscriber.undeclare().wait(),
+        _ => unreachable!("entity kind changed after subscriber check"),
+    }
+    .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))?;
 
     Ok(rustler::types::atom::ok())
 }
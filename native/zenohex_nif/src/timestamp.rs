@@ -0,0 +1,95 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(rustler::NifStruct)]
+#[module = "Zenohex.Timestamp"]
+pub struct ZenohexTimestamp {
+    time: u64,
+    id: String,
+}
+
+impl From<&zenoh::time::Timestamp> for ZenohexTimestamp {
+    fn from(timestamp: &zenoh::time::Timestamp) -> Self {
+        ZenohexTimestamp {
+            time: timestamp.get_time().as_u64(),
+            id: timestamp.get_id().to_string(),
+        }
+    }
+}
+
+impl TryFrom<ZenohexTimestamp> for zenoh::time::Timestamp {
+    type Error = rustler::Error;
+
+    fn try_from(value: ZenohexTimestamp) -> Result<Self, Self::Error> {
+        let id = zenoh::time::TimestampId::from_str(&value.id)
+            .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error.cause)))?;
+
+        Ok(zenoh::time::Timestamp::new(
+            zenoh::time::NTP64(value.time),
+            id,
+        ))
+    }
+}
+
+#[rustler::nif]
+fn timestamp_parse(string: &str) -> rustler::NifResult<(rustler::Atom, ZenohexTimestamp)> {
+    let timestamp = zenoh::time::Timestamp::parse_rfc3339(string)
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error.cause)))?;
+
+    Ok((rustler::types::atom::ok(), (&timestamp).into()))
+}
+
+// NOTE: Compare here rather than in Elixir,
+//       since the order of ids is the order of their bytes, not of their hex strings.
+#[rustler::nif]
+fn timestamp_compare(
+    timestamp1: ZenohexTimestamp,
+    timestamp2: ZenohexTimestamp,
+) -> rustler::NifResult<rustler::Atom> {
+    let timestamp1 = zenoh::time::Timestamp::try_from(timestamp1)?;
+    let timestamp2 = zenoh::time::Timestamp::try_from(timestamp2)?;
+
+    match timestamp1.cmp(&timestamp2) {
+        std::cmp::Ordering::Less => Ok(crate::atoms::lt()),
+        std::cmp::Ordering::Equal => Ok(crate::atoms::eq()),
+        std::cmp::Ordering::Greater => Ok(crate::atoms::gt()),
+    }
+}
+
+#[rustler::nif]
+fn timestamp_to_string(timestamp: ZenohexTimestamp) -> rustler::NifResult<String> {
+    let timestamp = zenoh::time::Timestamp::try_from(timestamp)?;
+
+    Ok(timestamp.to_string_rfc3339_lossy())
+}
+
+// NOTE: NTP64 and unix time conversions are done here to keep the exact rounding of uhlc.
+//       NTP64 seconds fit in 32 bits, so unix nanoseconds always fit in a u64.
+#[rustler::nif]
+fn timestamp_time_to_unix_nanos(time: u64) -> u64 {
+    zenoh::time::NTP64(time).to_duration().as_nanos() as u64
+}
+
+// WHY: NTP64 seconds are 32 bits, so instants before the UNIX epoch or after 2106
+//      cannot be represented, and `NTP64::from(Duration)` panics on the latter.
+//      `nanos` is a term since integers out of the u64 range are out of the NTP64 range too.
+#[rustler::nif]
+fn timestamp_time_from_unix_nanos(
+    nanos: rustler::Term,
+) -> rustler::NifResult<(rustler::Atom, u64)> {
+    let duration = nanos
+        .decode::<u64>()
+        .ok()
+        .map(Duration::from_nanos)
+        .filter(|duration| duration.as_secs() <= u64::from(u32::MAX))
+        .ok_or_else(|| {
+            rustler::Error::Term(Box::new(format!(
+                "unix time {nanos:?}ns is out of the NTP64 range"
+            )))
+        })?;
+
+    Ok((
+        rustler::types::atom::ok(),
+        zenoh::time::NTP64::from(duration).as_u64(),
+    ))
+}
@@ -0,0 +1,25 @@
+use std::str::FromStr;
+
+fn parse(string: &str) -> rustler::NifResult<zenoh::session::ZenohId> {
+    zenoh::session::ZenohId::from_str(string)
+        .map_err(|error| rustler::Error::Term(crate::zenoh_error!(error)))
+}
+
+// NOTE: Formatting the parsed id back gives the canonical string of the id.
+#[rustler::nif]
+fn zenoh_id_parse(string: &str) -> rustler::NifResult<(rustler::Atom, String)> {
+    let zid = parse(string)?;
+
+    Ok((rustler::types::atom::ok(), zid.to_string()))
+}
+
+// NOTE: Compare here rather than in Elixir,
+//       since the order of ids is the order of their bytes, not of their hex strings.
+#[rustler::nif]
+fn zenoh_id_compare(zid1: &str, zid2: &str) -> rustler::NifResult<rustler::Atom> {
+    match parse(zid1)?.cmp(&parse(zid2)?) {
+        std::cmp::Ordering::Less => Ok(crate::atoms::lt()),
+        std::cmp::Ordering::Equal => Ok(crate::atoms::eq()),
+        std::cmp::Ordering::Greater => Ok(crate::atoms::gt()),
+    }
+}
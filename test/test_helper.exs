@@ -1 +1,9 @@
-ExUnit.start()
+# WHY: Tests of statically linked plugins only run when the NIF is built with their feature.
+nif_features = Application.get_env(:zenohex, :nif_features, [])
+
+excluded_features =
+  for feature <- [:plugin_rest, :plugin_storage_manager],
+      Atom.to_string(feature) not in nif_features,
+      do: feature
+
+ExUnit.start(exclude: excluded_features)
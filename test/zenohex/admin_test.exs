@@ -0,0 +1,67 @@
+defmodule Zenohex.AdminTest do
+  use ExUnit.Case
+
+  setup do
+    {:ok, session_id} =
+      Zenohex.Config.default()
+      |> Zenohex.Test.Support.TestHelper.scouting_delay(0)
+      |> insert_json5("adminspace/enabled", "true")
+      |> Zenohex.Session.open()
+
+    {:ok, %Zenohex.Session.Info{zid: zid}} = Zenohex.Session.info(session_id)
+
+    on_exit(fn -> Zenohex.Session.close(session_id) end)
+
+    %{session_id: session_id, zid: zid}
+  end
+
+  test "nodes/2 includes the local session", context do
+    zid = context.zid
+
+    assert {:ok, nodes} = Zenohex.Admin.nodes(context.session_id)
+    assert %Zenohex.Admin.Node{whatami: :peer, locators: locators} = find(nodes, zid)
+    assert is_list(locators)
+
+    assert {:ok, peers} = Zenohex.Admin.peers(context.session_id)
+    assert %Zenohex.Admin.Node{} = find(peers, zid)
+
+    assert {:ok, routers} = Zenohex.Admin.routers(context.session_id)
+    assert nil == find(routers, zid)
+  end
+
+  test "subscribers/2 and queryables/2 include the local entities", context do
+    zid = context.zid
+
+    {:ok, _subscriber_id} =
+      Zenohex.Session.declare_subscriber(context.session_id, "admin/subscriber", self())
+
+    {:ok, _queryable_id} =
+      Zenohex.Session.declare_queryable(context.session_id, "admin/queryable", self())
+
+    assert {:ok, subscribers} = Zenohex.Admin.subscribers(context.session_id)
+
+    assert Enum.any?(
+             subscribers,
+             &match?(%{zid: ^zid, kind: :subscriber, key_expr: "admin/subscriber"}, &1)
+           )
+
+    assert {:ok, queryables} = Zenohex.Admin.queryables(context.session_id)
+
+    assert Enum.any?(
+             queryables,
+             &match?(%{zid: ^zid, kind: :queryable, key_expr: "admin/queryable"}, &1)
+           )
+  end
+
+  test "linkstate/2", context do
+    assert {:ok, graphs} = Zenohex.Admin.linkstate(context.session_id)
+    assert Enum.all?(graphs, &match?(%Zenohex.Admin.Graph{}, &1))
+  end
+
+  defp insert_json5(config, key, value) do
+    {:ok, updated_config} = Zenohex.Config.insert_json5(config, key, value)
+    updated_config
+  end
+
+  defp find(nodes, zid), do: Enum.find(nodes, &(&1.zid == zid))
+end
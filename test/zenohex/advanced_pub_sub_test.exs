@@ -0,0 +1,98 @@
+defmodule Zenohex.AdvancedPubSubTest do
+  use ExUnit.Case
+
+  setup do
+    {:ok, session_id} =
+      Zenohex.Config.default()
+      |> Zenohex.Test.Support.TestHelper.scouting_delay(0)
+      |> Zenohex.Session.open()
+
+    on_exit(fn -> Zenohex.Session.close(session_id) end)
+
+    %{session_id: session_id}
+  end
+
+  test "declare_advanced_subscriber/4 with history receives cached samples", context do
+    {:ok, publisher_id} =
+      Zenohex.Session.declare_advanced_publisher(context.session_id, "key/expr",
+        cache: [max_samples: 2],
+        sample_miss_detection: true,
+        publisher_detection: true
+      )
+
+    for i <- 1..3 do
+      :ok = Zenohex.Publisher.put(publisher_id, "#{i}")
+    end
+
+    {:ok, _subscriber_id} =
+      Zenohex.Session.declare_advanced_subscriber(context.session_id, "key/expr", self(),
+        history: [detect_late_publishers: true],
+        recovery: :heartbeat,
+        subscriber_detection: true
+      )
+
+    assert_receive %Zenohex.Sample{payload: "2"}
+    assert_receive %Zenohex.Sample{payload: "3"}
+    refute_receive %Zenohex.Sample{payload: "1"}
+
+    :ok = Zenohex.Publisher.put(publisher_id, "4")
+    assert_receive %Zenohex.Sample{payload: "4"}
+  end
+
+  test "declare_advanced_subscriber/4 sends a sample miss for a gap in sequence numbers",
+       context do
+    {:ok, _subscriber_id} =
+      Zenohex.Session.declare_advanced_subscriber(context.session_id, "key/expr", self())
+
+    # WHY: Samples are sequenced by their source info, so setting it on plain puts
+    #      lets the test skip sequence numbers as if samples had been lost.
+    {:ok, %Zenohex.Session.Info{zid: zid}} = Zenohex.Session.info(context.session_id)
+    source_id = %Zenohex.EntityGlobalId{zid: zid, eid: 1}
+
+    for {payload, source_sn} <- [{"1", 1}, {"4", 4}] do
+      :ok =
+        Zenohex.Session.put(context.session_id, "key/expr", payload,
+          source_info: %Zenohex.SourceInfo{source_id: source_id, source_sn: source_sn}
+        )
+    end
+
+    assert_receive %Zenohex.Sample{payload: "1"}
+    assert_receive %Zenohex.Sample{payload: "4"}
+    assert_receive %Zenohex.SampleMiss{source: ^source_id, nb: 2}
+  end
+
+  test "Publisher.put/3 of an advanced publisher passes source_info through", context do
+    {:ok, publisher_id} =
+      Zenohex.Session.declare_advanced_publisher(context.session_id, "key/expr")
+
+    {:ok, _subscriber_id} =
+      Zenohex.Session.declare_subscriber(context.session_id, "key/expr", self())
+
+    {:ok, %Zenohex.Session.Info{zid: zid}} = Zenohex.Session.info(context.session_id)
+    source_info = %Zenohex.SourceInfo{
+      source_id: %Zenohex.EntityGlobalId{zid: zid, eid: 1},
+      source_sn: 7
+    }
+
+    :ok = Zenohex.Publisher.put(publisher_id, "put", source_info: source_info)
+    assert_receive %Zenohex.Sample{payload: "put", source_info: ^source_info}
+
+    :ok = Zenohex.Publisher.delete(publisher_id, source_info: source_info)
+    assert_receive %Zenohex.Sample{kind: :delete, source_info: ^source_info}
+  end
+
+  test "advanced entities are undeclared like plain ones", context do
+    {:ok, publisher_id} =
+      Zenohex.Session.declare_advanced_publisher(context.session_id, "key/expr")
+
+    {:ok, subscriber_id} =
+      Zenohex.Session.declare_advanced_subscriber(context.session_id, "key/expr")
+
+    assert :ok = Zenohex.Publisher.delete(publisher_id)
+    assert_receive %Zenohex.Sample{kind: :delete}
+
+    assert :ok = Zenohex.Publisher.undeclare(publisher_id)
+    assert :ok = Zenohex.Subscriber.undeclare(subscriber_id)
+    assert {:error, _} = Zenohex.Subscriber.undeclare(subscriber_id)
+  end
+end
@@ -0,0 +1,43 @@
+defmodule Zenohex.CancellationTokenTest do
+  use ExUnit.Case
+
+  setup do
+    {:ok, session_id} =
+      Zenohex.Config.default()
+      |> Zenohex.Test.Support.TestHelper.scouting_delay(0)
+      |> Zenohex.Session.open()
+
+    {:ok, queryable_id} = Zenohex.Session.declare_queryable(session_id, "key/expr/**", self())
+
+    on_exit(fn ->
+      :ok = Zenohex.Queryable.undeclare(queryable_id)
+      :ok = Zenohex.Session.close(session_id)
+    end)
+
+    %{session_id: session_id, queryable_id: queryable_id}
+  end
+
+  test "cancel/1 marks the token as cancelled" do
+    token = Zenohex.CancellationToken.new()
+    refute Zenohex.CancellationToken.cancelled?(token)
+
+    assert :ok = Zenohex.CancellationToken.cancel(token)
+    assert Zenohex.CancellationToken.cancelled?(token)
+  end
+
+  test "cancel/1 stops an in-flight async get", context do
+    token = Zenohex.CancellationToken.new()
+
+    {:ok, ref} =
+      Zenohex.Session.get_async(context.session_id, "key/expr/1", self(),
+        cancellation_token: token
+      )
+
+    assert_receive %Zenohex.Query{zenoh_query: zenoh_query}
+    assert :ok = Zenohex.CancellationToken.cancel(token)
+    assert_receive {:zenohex_get_done, ^ref}
+
+    _ = Zenohex.Query.reply(zenoh_query, "key/expr/1", "payload")
+    refute_receive {:zenohex_get_reply, ^ref, _}
+  end
+end
@@ -0,0 +1,95 @@
+defmodule Zenohex.ConnectivityTest do
+  use ExUnit.Case
+
+  setup do
+    {:ok, session_id} =
+      Zenohex.Config.default()
+      |> Zenohex.Test.Support.TestHelper.scouting_delay(0)
+      |> insert_json5("scouting/multicast/enabled", "false")
+      |> insert_json5("listen/endpoints", ["tcp/127.0.0.1:0"])
+      |> Zenohex.Session.open()
+
+    # WHY: Port 0 lets the OS pick a free port, the bound one is read back from the locators.
+    {:ok, %Zenohex.Session.Info{zid: zid, locators: [endpoint | _]}} =
+      Zenohex.Session.info(session_id)
+
+    on_exit(fn -> Zenohex.Session.close(session_id) end)
+
+    %{session_id: session_id, zid: zid, endpoint: endpoint}
+  end
+
+  defp insert_json5(config, key, value) do
+    {:ok, updated_config} = Zenohex.Config.insert_json5(config, key, value)
+    updated_config
+  end
+
+  defp open_remote_session(endpoint) do
+    Zenohex.Config.default()
+    |> Zenohex.Test.Support.TestHelper.scouting_delay(0)
+    |> insert_json5("scouting/multicast/enabled", "false")
+    |> insert_json5("listen/endpoints", [])
+    |> insert_json5("connect/endpoints", [endpoint])
+    |> Zenohex.Session.open()
+  end
+
+  test "declare_listener/3 receives transport opened and closed", context do
+    {:ok, listener_id} = Zenohex.Connectivity.declare_listener(context.session_id, self())
+
+    {:ok, remote_session_id} = open_remote_session(context.endpoint)
+    {:ok, %Zenohex.Session.Info{zid: remote_zid}} = Zenohex.Session.info(remote_session_id)
+
+    assert_receive {:zenohex_transport, :opened,
+                    %{zid: ^remote_zid, whatami: :peer, links: [%{src: _, dst: _} | _]}},
+                   1000
+
+    :ok = Zenohex.Session.close(remote_session_id)
+
+    assert_receive {:zenohex_transport, :closed, %{zid: ^remote_zid, whatami: :peer}}, 1000
+
+    assert :ok = Zenohex.Connectivity.undeclare_listener(listener_id)
+    assert {:error, _reason} = Zenohex.Connectivity.undeclare_listener(listener_id)
+  end
+
+  test "declare_listener/3 with history receives already opened transports", context do
+    {:ok, remote_session_id} = open_remote_session(context.endpoint)
+    on_exit(fn -> Zenohex.Session.close(remote_session_id) end)
+
+    {:ok, %Zenohex.Session.Info{zid: remote_zid}} = Zenohex.Session.info(remote_session_id)
+    {:ok, remote_listener_id} =
+      Zenohex.Connectivity.declare_listener(remote_session_id, self(), history: true)
+
+    assert_receive {:zenohex_transport, :opened, %{zid: zid}}, 1000
+    assert zid == context.zid
+
+    {:ok, _listener_id} =
+      Zenohex.Connectivity.declare_listener(context.session_id, self(), history: true)
+
+    assert_receive {:zenohex_transport, :opened, %{zid: ^remote_zid}}
+
+    :ok = Zenohex.Connectivity.undeclare_listener(remote_listener_id)
+  end
+
+  test "declare_listener/3 receives session closed", context do
+    {:ok, _listener_id} = Zenohex.Connectivity.declare_listener(context.session_id, self())
+
+    :ok = Zenohex.Session.close(context.session_id)
+
+    zid = context.zid
+    assert_receive {:zenohex_session, :closed, %{zid: ^zid, reason: :close}}
+  end
+
+  test "declare_listener/3 receives session closed by drop", context do
+    test_pid = self()
+    endpoint = context.endpoint
+
+    # The session id is only held by this process, so it is garbage-collected when it exits.
+    spawn(fn ->
+      {:ok, remote_session_id} = open_remote_session(endpoint)
+      {:ok, listener_id} = Zenohex.Connectivity.declare_listener(remote_session_id, test_pid)
+      send(test_pid, {:listener_id, listener_id})
+    end)
+
+    assert_receive {:listener_id, _listener_id}, 1000
+    assert_receive {:zenohex_session, :closed, %{reason: :drop}}, 1000
+  end
+end
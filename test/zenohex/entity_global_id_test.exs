@@ -0,0 +1,45 @@
+defmodule Zenohex.EntityGlobalIdTest do
+  use ExUnit.Case
+
+  setup do
+    {:ok, session_id} =
+      Zenohex.Config.default()
+      |> Zenohex.Test.Support.TestHelper.scouting_delay(0)
+      |> Zenohex.Session.open()
+
+    on_exit(fn -> Zenohex.Session.close(session_id) end)
+
+    %{session_id: session_id}
+  end
+
+  test "from_entity/1 returns the zid of the session", context do
+    {:ok, %Zenohex.Session.Info{zid: zid}} = Zenohex.Session.info(context.session_id)
+    {:ok, publisher_id} = Zenohex.Session.declare_publisher(context.session_id, "key/expr")
+    {:ok, subscriber_id} =
+      Zenohex.Session.declare_subscriber(context.session_id, "key/expr", self())
+
+    publisher_global_id = Zenohex.EntityGlobalId.from_entity(publisher_id)
+    subscriber_global_id = Zenohex.EntityGlobalId.from_entity(subscriber_id)
+
+    assert Zenohex.EntityGlobalId.zid(publisher_global_id) == zid
+    assert Zenohex.EntityGlobalId.zid(subscriber_global_id) == zid
+
+    assert Zenohex.EntityGlobalId.eid(publisher_global_id) !=
+             Zenohex.EntityGlobalId.eid(subscriber_global_id)
+  end
+
+  test "source_info built from a publisher identifies it on received samples", context do
+    {:ok, publisher_id} = Zenohex.Session.declare_publisher(context.session_id, "key/expr")
+    {:ok, _subscriber_id} =
+      Zenohex.Session.declare_subscriber(context.session_id, "key/expr", self())
+
+    publisher_global_id = Zenohex.EntityGlobalId.from_entity(publisher_id)
+    source_info = %Zenohex.SourceInfo{source_id: publisher_global_id, source_sn: 0}
+
+    :ok = Zenohex.Publisher.put(publisher_id, "payload", source_info: source_info)
+
+    assert_receive %Zenohex.Sample{
+      source_info: %Zenohex.SourceInfo{source_id: ^publisher_global_id}
+    }
+  end
+end
@@ -0,0 +1,35 @@
+defmodule Zenohex.KeyExpr.FormatTest do
+  use ExUnit.Case
+
+  alias Zenohex.KeyExpr.Format
+
+  setup do
+    {:ok, format} = Format.new("fleet/${robot:*}/sensor/${kind:*}/${id:**#all}")
+    %{format: format}
+  end
+
+  test "new/1 returns an error for an invalid format" do
+    assert {:error, _} = Format.new("fleet/${robot:*")
+  end
+
+  test "format/2", %{format: format} do
+    assert {:ok, "fleet/r1/sensor/lidar/0"} =
+             Format.format(format, robot: "r1", kind: "lidar", id: 0)
+
+    assert {:ok, "fleet/r1/sensor/lidar/all"} =
+             Format.format(format, %{"robot" => "r1", "kind" => "lidar"})
+  end
+
+  test "format/2 validates the values", %{format: format} do
+    assert {:error, _} = Format.format(format, robot: "r1/r2", kind: "lidar")
+    assert {:error, _} = Format.format(format, robot: "r1", kind: "lidar", unknown: "x")
+    assert {:error, _} = Format.format(format, robot: "r1")
+  end
+
+  test "parse/2", %{format: format} do
+    assert {:ok, %{"robot" => "r2", "kind" => "imu", "id" => "a/b"}} =
+             Format.parse(format, "fleet/r2/sensor/imu/a/b")
+
+    assert {:error, _} = Format.parse(format, "drone/r2/sensor/imu")
+  end
+end
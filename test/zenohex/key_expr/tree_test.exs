@@ -0,0 +1,45 @@
+defmodule Zenohex.KeyExpr.TreeTest do
+  use ExUnit.Case
+
+  alias Zenohex.KeyExpr.Tree
+
+  setup do
+    tree = Tree.new()
+    :ok = Tree.insert(tree, "robot/*/pose", :pose)
+    :ok = Tree.insert(tree, "robot/**", :robot)
+    :ok = Tree.insert(tree, "robot/1/speed", :speed)
+
+    %{tree: tree}
+  end
+
+  test "intersecting/2", %{tree: tree} do
+    assert Enum.sort(Tree.intersecting(tree, "robot/1/*")) == [:pose, :robot, :speed]
+    assert Tree.intersecting(tree, "drone/**") == []
+  end
+
+  test "including/2", %{tree: tree} do
+    assert Enum.sort(Tree.including(tree, "robot/1/pose")) == [:pose, :robot]
+    assert Tree.including(tree, "robot") == [:robot]
+  end
+
+  test "included/2", %{tree: tree} do
+    assert Enum.sort(Tree.included(tree, "robot/1/**")) == [:speed]
+    assert Enum.sort(Tree.included(tree, "**")) == [:pose, :robot, :speed]
+  end
+
+  test "insert/3 replaces the term", %{tree: tree} do
+    :ok = Tree.insert(tree, "robot/1/speed", %{unit: "m/s"})
+    assert Tree.included(tree, "robot/1/speed") == [%{unit: "m/s"}]
+  end
+
+  test "remove/2", %{tree: tree} do
+    assert :ok = Tree.remove(tree, "robot/**")
+    assert {:error, :not_found} = Tree.remove(tree, "robot/**")
+    assert Tree.including(tree, "robot/1/pose") == [:pose]
+  end
+
+  test "raises on invalid key expressions", %{tree: tree} do
+    assert_raise ArgumentError, fn -> Tree.insert(tree, "invalid/key/expr?", :invalid) end
+    assert_raise ArgumentError, fn -> Tree.including(tree, "invalid/key/expr?") end
+  end
+end
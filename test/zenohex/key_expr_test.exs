@@ -36,4 +36,113 @@ defmodule Zenohex.KeyExprTest do
   test "join/2 returns canonized key expression" do
     assert {:ok, "key/expr/*/**"} == Zenohex.KeyExpr.join("key/expr/**", "*")
   end
+
+  test "canonize_strict/1" do
+    assert {:ok, "key/expr/*/**"} = Zenohex.KeyExpr.canonize_strict("key/expr/*/**")
+    assert {:error, {:not_canon, "key/expr/*/**"}} =
+             Zenohex.KeyExpr.canonize_strict("key/expr/**/*")
+    assert {:error, reason} = Zenohex.KeyExpr.canonize_strict("invalid/key/expr?")
+    assert is_binary(reason)
+  end
+
+  test "validate/1" do
+    assert :ok = Zenohex.KeyExpr.validate("key/expr/*/**")
+
+    for {key_expr, reason, position} <- [
+          {"", :empty_chunk, 0},
+          {"key//expr", :empty_chunk, 4},
+          {"key/expr/", :empty_chunk, 9},
+          {"key/ex*pr", :star_in_chunk, 6},
+          {"key/**/*", :single_star_after_double_star, 7},
+          {"key/**/**", :double_star_after_double_star, 7},
+          {"key/$*", :lone_dollar_star, 4},
+          {"key/a$*$b", :dollar_after_dollar, 7},
+          {"key/a$b", :unbound_dollar, 5},
+          {"key/expr?", :forbidden_character, 8}
+        ] do
+      assert {:error, %Zenohex.KeyExpr.ValidationError{reason: ^reason, position: ^position}} =
+               Zenohex.KeyExpr.validate(key_expr)
+    end
+  end
+
+  # WHY: `validate/1` reasons are found by rules written apart from zenoh,
+  #      so they must agree with zenoh on what is invalid, without falling back to `:other`.
+  test "validate/1 finds a reason for every key expression zenoh rejects" do
+    for key_expr <- [
+          "",
+          "/",
+          "/key",
+          "key/",
+          "key//expr",
+          "*key",
+          "key*",
+          "key/ex*pr",
+          "key/***",
+          "key/**/*",
+          "key/**/**",
+          "key/**/*/**",
+          "$*",
+          "key/$*",
+          "key/a$*$b",
+          "key/a$*$*",
+          "key/$",
+          "key/a$b",
+          "key/a$",
+          "key/expr?",
+          "key/#",
+          "key?/expr#"
+        ] do
+      refute Zenohex.KeyExpr.valid?(key_expr)
+
+      assert {:error, %Zenohex.KeyExpr.ValidationError{reason: reason, position: position}} =
+               Zenohex.KeyExpr.validate(key_expr)
+
+      assert reason != :other, "no reason found for #{inspect(key_expr)}"
+      assert is_integer(position)
+    end
+
+    for key_expr <- ["key", "key/*", "key/**", "*/**", "key/a$*", "key/$*a$*b", "@/key"] do
+      assert Zenohex.KeyExpr.valid?(key_expr)
+      assert :ok = Zenohex.KeyExpr.validate(key_expr)
+    end
+  end
+
+  test "relation_to/2" do
+    assert :disjoint = Zenohex.KeyExpr.relation_to("key/a", "key/b")
+    assert :intersects = Zenohex.KeyExpr.relation_to("key/*/c", "key/b/*")
+    assert :includes = Zenohex.KeyExpr.relation_to("key/**", "key/b")
+    assert :equals = Zenohex.KeyExpr.relation_to("key/b", "key/b")
+  end
+
+  test "concat/2" do
+    assert {:ok, "key/expr_suffix"} = Zenohex.KeyExpr.concat("key/expr", "_suffix")
+    assert {:error, _} = Zenohex.KeyExpr.concat("key/*", "*/expr")
+    assert {:error, _} = Zenohex.KeyExpr.concat("key/expr", "/")
+  end
+
+  test "chunks/1" do
+    assert ["key", "*", "expr"] = Zenohex.KeyExpr.chunks("key/*/expr")
+    assert_raise ArgumentError, fn -> Zenohex.KeyExpr.chunks("invalid/key/expr?") end
+  end
+
+  test "wild?/1" do
+    assert Zenohex.KeyExpr.wild?("key/**")
+    assert Zenohex.KeyExpr.wild?("key/ex$*")
+    refute Zenohex.KeyExpr.wild?("key/expr")
+  end
+
+  test "strip_prefix/2" do
+    assert ["abc"] = Zenohex.KeyExpr.strip_prefix("demo/example/test/abc", "demo/example/test")
+    assert ["*", "**/c/*"] = Zenohex.KeyExpr.strip_prefix("a/**/c/*", "a/b/c")
+    assert [] = Zenohex.KeyExpr.strip_prefix("demo/example", "not/a/prefix")
+  end
+
+  test "strip_nonwild_prefix/2" do
+    assert "**/test" = Zenohex.KeyExpr.strip_nonwild_prefix("demo/**/test", "demo/example")
+    assert nil == Zenohex.KeyExpr.strip_nonwild_prefix("demo/example", "not/a/prefix")
+
+    assert_raise ArgumentError, fn ->
+      Zenohex.KeyExpr.strip_nonwild_prefix("demo/example", "demo/*")
+    end
+  end
 end
@@ -66,4 +66,15 @@ defmodule Zenohex.LivelinessTest do
     assert {:ok, [%Zenohex.Sample{kind: :put}]} =
              Zenohex.Liveliness.get(context.session_id, "key/expr", 100)
   end
+
+  test "get_async/2", context do
+    {:ok, token} = Zenohex.Liveliness.declare_token(context.session_id, "key/expr")
+
+    on_exit(fn -> :ok = Zenohex.Liveliness.undeclare_token(token) end)
+
+    assert {:ok, ref} = Zenohex.Liveliness.get_async(context.session_id, "key/expr")
+
+    assert_receive {:zenohex_get_reply, ^ref, %Zenohex.Sample{kind: :put}}
+    assert_receive {:zenohex_get_done, ^ref}
+  end
 end
@@ -0,0 +1,62 @@
+defmodule Zenohex.LvcTest do
+  use ExUnit.Case
+
+  setup do
+    {:ok, session_id} =
+      Zenohex.Config.default()
+      |> Zenohex.Test.Support.TestHelper.scouting_delay(0)
+      |> Zenohex.Session.open()
+
+    on_exit(fn -> Zenohex.Session.close(session_id) end)
+
+    {:ok, lvc} = Zenohex.Lvc.declare(session_id, "robot/*/pose")
+
+    %{session_id: session_id, lvc: lvc}
+  end
+
+  test "get/2 returns the latest sample", context do
+    :ok = Zenohex.Session.put(context.session_id, "robot/1/pose", "0,0")
+    :ok = Zenohex.Session.put(context.session_id, "robot/1/pose", "1,2")
+
+    assert {:ok, %Zenohex.Sample{key_expr: "robot/1/pose", payload: "1,2"}} =
+             Zenohex.Lvc.get(context.lvc, "robot/1/pose")
+
+    assert {:error, :not_found} = Zenohex.Lvc.get(context.lvc, "robot/2/pose")
+  end
+
+  test "match/2 and keys/1", context do
+    :ok = Zenohex.Session.put(context.session_id, "robot/1/pose", "1")
+    :ok = Zenohex.Session.put(context.session_id, "robot/2/pose", "2")
+    :ok = Zenohex.Session.put(context.session_id, "robot/3/speed", "3")
+
+    assert {:ok, keys} = Zenohex.Lvc.keys(context.lvc)
+    assert Enum.sort(keys) == ["robot/1/pose", "robot/2/pose"]
+
+    assert {:ok, [%Zenohex.Sample{payload: "2"}]} = Zenohex.Lvc.match(context.lvc, "robot/2/*")
+    assert {:ok, samples} = Zenohex.Lvc.match(context.lvc, "robot/**")
+    assert length(samples) == 2
+  end
+
+  test "delete samples remove the key", context do
+    :ok = Zenohex.Session.put(context.session_id, "robot/1/pose", "1")
+    :ok = Zenohex.Session.delete(context.session_id, "robot/1/pose")
+
+    assert {:error, :not_found} = Zenohex.Lvc.get(context.lvc, "robot/1/pose")
+    assert {:ok, []} = Zenohex.Lvc.keys(context.lvc)
+  end
+
+  test "undeclare/1 keeps the cached samples", context do
+    :ok = Zenohex.Session.put(context.session_id, "robot/1/pose", "1")
+
+    assert :ok = Zenohex.Lvc.undeclare(context.lvc)
+    assert {:error, _} = Zenohex.Lvc.undeclare(context.lvc)
+
+    :ok = Zenohex.Session.put(context.session_id, "robot/1/pose", "2")
+    assert {:ok, %Zenohex.Sample{payload: "1"}} = Zenohex.Lvc.get(context.lvc, "robot/1/pose")
+  end
+
+  test "raises on invalid key expressions", context do
+    assert_raise ArgumentError, fn -> Zenohex.Lvc.get(context.lvc, "invalid/key/expr?") end
+    assert_raise ArgumentError, fn -> Zenohex.Lvc.match(context.lvc, "invalid/key/expr?") end
+  end
+end
@@ -0,0 +1,125 @@
+defmodule Zenohex.PluginsTest do
+  use ExUnit.Case
+
+  setup do
+    {:ok, config} =
+      Zenohex.Config.default()
+      |> Zenohex.Test.Support.TestHelper.scouting_delay(0)
+      |> Zenohex.Config.insert_json5("plugins_loading/enabled", "true")
+
+    {:ok, session_id} = Zenohex.Session.open(config)
+
+    on_exit(fn -> Zenohex.Session.close(session_id) end)
+
+    %{session_id: session_id}
+  end
+
+  test "list/1 returns no plugin if none is configured", context do
+    assert {:ok, []} = Zenohex.Plugins.list(context.session_id)
+  end
+
+  test "start/3 with a bad path returns an error and lists the plugin as declared", context do
+    assert {:error, _reason} =
+             Zenohex.Plugins.start(context.session_id, "bad",
+               paths: ["/nonexistent/libzenoh_plugin_bad.so"]
+             )
+
+    assert {:ok, [plugin]} = Zenohex.Plugins.list(context.session_id)
+
+    assert %Zenohex.Plugins.Plugin{
+             id: "bad",
+             state: :declared,
+             report: %Zenohex.Plugins.Report{level: :error, messages: [_ | _]}
+           } = plugin
+  end
+
+  test "stop/2 and reload/3 return an error for a plugin which is not loaded", context do
+    assert {:error, _reason} = Zenohex.Plugins.stop(context.session_id, "unknown")
+    assert {:error, _reason} = Zenohex.Plugins.reload(context.session_id, "unknown")
+  end
+
+  test "status/2 returns no entry for a plugin which is not started", context do
+    assert {:ok, status} = Zenohex.Plugins.status(context.session_id, "unknown")
+    assert status == %{}
+  end
+
+  @tag :plugin_storage_manager
+  test "start/3 starts the statically linked storage manager with the memory volume", context do
+    assert :ok =
+             Zenohex.Plugins.start(context.session_id, "storage_manager",
+               config: ~s({storages: {demo: {key_expr: "plugins/demo/**", volume: "memory"}}})
+             )
+
+    assert {:ok, [plugin]} = Zenohex.Plugins.list(context.session_id)
+
+    assert %Zenohex.Plugins.Plugin{
+             id: "storage_manager",
+             path: "__static_lib__",
+             state: :started
+           } = plugin
+
+    assert {:ok, %{"__path__" => "__static_lib__"}} =
+             Zenohex.Plugins.status(context.session_id, "storage_manager")
+
+    assert [%Zenohex.Sample{key_expr: "plugins/demo/key", payload: "value"}] =
+             put_and_get_eventually(context.session_id, "plugins/demo/key", "value")
+  end
+
+  @tag :plugin_rest
+  test "start/3 starts the statically linked REST plugin on http_port", context do
+    # WHY: Let the OS pick a free port, then hand it over to the plugin.
+    {:ok, socket} = :gen_tcp.listen(0, ip: {127, 0, 0, 1})
+    {:ok, port} = :inet.port(socket)
+    :ok = :gen_tcp.close(socket)
+
+    assert :ok =
+             Zenohex.Plugins.start(context.session_id, "rest",
+               config: ~s({http_port: "127.0.0.1:#{port}"})
+             )
+
+    assert {:ok, [plugin]} = Zenohex.Plugins.list(context.session_id)
+
+    assert %Zenohex.Plugins.Plugin{id: "rest", path: "__static_lib__", state: :started} =
+             plugin
+
+    assert {:ok, response} = http_get_eventually(port, "/plugins/rest/**")
+    assert response =~ ~r"^HTTP/1\.\d 200"
+  end
+
+  # WHY: The REST plugin binds its port asynchronously once it is started,
+  #      so the connection is retried until it listens.
+  defp http_get_eventually(port, path, attempts \\ 20) do
+    case :gen_tcp.connect({127, 0, 0, 1}, port, [:binary, active: false], 1000) do
+      {:ok, socket} ->
+        :ok = :gen_tcp.send(socket, "GET #{path} HTTP/1.0\r\nHost: 127.0.0.1\r\n\r\n")
+        response = :gen_tcp.recv(socket, 0, 1000)
+        :ok = :gen_tcp.close(socket)
+        response
+
+      {:error, _reason} when attempts > 1 ->
+        Process.sleep(100)
+        http_get_eventually(port, path, attempts - 1)
+
+      error ->
+        error
+    end
+  end
+
+  # WHY: The storage is created asynchronously once the plugin is started,
+  #      so the put is retried until the storage has stored it.
+  defp put_and_get_eventually(session_id, key_expr, payload, attempts \\ 20) do
+    :ok = Zenohex.Session.put(session_id, key_expr, payload)
+
+    case Zenohex.Session.get(session_id, key_expr, 100) do
+      {:ok, [_ | _] = replies} ->
+        replies
+
+      _ when attempts > 1 ->
+        Process.sleep(100)
+        put_and_get_eventually(session_id, key_expr, payload, attempts - 1)
+
+      other ->
+        other
+    end
+  end
+end
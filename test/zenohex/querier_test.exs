@@ -71,20 +71,34 @@ defmodule Zenohex.QuerierTest do
   end
 
   test "get_async/2 delivers put replies to pid", context do
-    assert :ok = Zenohex.Querier.get_async(context.querier_id, self())
+    assert {:ok, ref} = Zenohex.Querier.get_async(context.querier_id, self())
 
     assert_receive %Zenohex.Query{zenoh_query: zenoh_query}
     assert :ok = Zenohex.Query.reply(zenoh_query, "key/expr/1", "async_payload")
 
-    assert_receive %Zenohex.Sample{kind: :put, key_expr: "key/expr/1", payload: "async_payload"}
+    assert_receive {:zenohex_get_reply, ^ref,
+                    %Zenohex.Sample{kind: :put, key_expr: "key/expr/1", payload: "async_payload"}}
+
+    assert_receive {:zenohex_get_done, ^ref}
   end
 
   test "get_async/2 delivers error replies to pid", context do
-    assert :ok = Zenohex.Querier.get_async(context.querier_id, self())
+    assert {:ok, ref} = Zenohex.Querier.get_async(context.querier_id, self())
 
     assert_receive %Zenohex.Query{zenoh_query: zenoh_query}
     assert :ok = Zenohex.Query.reply_error(zenoh_query, "error_payload")
 
-    assert_receive %Zenohex.Query.ReplyError{payload: "error_payload"}
+    assert_receive {:zenohex_get_reply, ^ref, %Zenohex.Query.ReplyError{payload: "error_payload"}}
+    assert_receive {:zenohex_get_done, ^ref}
+  end
+
+  test "get_async/3 tags replies with the given ref", context do
+    assert {:ok, :my_ref} = Zenohex.Querier.get_async(context.querier_id, self(), ref: :my_ref)
+
+    assert_receive %Zenohex.Query{zenoh_query: zenoh_query}
+    assert :ok = Zenohex.Query.reply(zenoh_query, "key/expr/1", "payload")
+
+    assert_receive {:zenohex_get_reply, :my_ref, %Zenohex.Sample{payload: "payload"}}
+    assert_receive {:zenohex_get_done, :my_ref}
   end
 end
@@ -36,6 +36,32 @@ defmodule Zenohex.QueryTest do
              Task.await(task)
   end
 
+  test "reply/4 and get/4 accept source_info", context do
+    {:ok, %Zenohex.Session.Info{zid: zid}} = Zenohex.Session.info(context.session_id)
+    query_source_id = %Zenohex.EntityGlobalId{zid: zid, eid: 1}
+    reply_source_id = %Zenohex.EntityGlobalId{zid: zid, eid: 2}
+    query_source_info = %Zenohex.SourceInfo{source_id: query_source_id, source_sn: 1}
+    reply_source_info = %Zenohex.SourceInfo{source_id: reply_source_id, source_sn: 2}
+
+    task =
+      Task.async(Zenohex.Session, :get, [
+        context.session_id,
+        "key/expr/**",
+        100,
+        [source_info: query_source_info]
+      ])
+
+    assert_receive %Zenohex.Query{zenoh_query: zenoh_query, source_info: ^query_source_info}
+
+    assert :ok =
+             Zenohex.Query.reply(zenoh_query, "key/expr/1", "payload",
+               final?: true,
+               source_info: reply_source_info
+             )
+
+    assert {:ok, [%Zenohex.Sample{source_info: ^reply_source_info}]} = Task.await(task)
+  end
+
   test "reply_error/3", context do
     task =
       Task.async(Zenohex.Session, :get, [
@@ -0,0 +1,88 @@
+defmodule Zenohex.ReceiverTest do
+  use ExUnit.Case
+
+  setup do
+    {:ok, session_id} =
+      Zenohex.Config.default()
+      |> Zenohex.Test.Support.TestHelper.scouting_delay(0)
+      |> Zenohex.Session.open()
+
+    on_exit(fn -> :ok = Zenohex.Session.close(session_id) end)
+
+    %{session_id: session_id}
+  end
+
+  test "recv/2 receives samples from a pull subscriber", context do
+    {:ok, subscriber_id} =
+      Zenohex.Session.declare_pull_subscriber(context.session_id, "key/expr", {:fifo, 16})
+
+    assert {:error, :timeout} = Zenohex.Receiver.recv(subscriber_id, 10)
+
+    :ok = Zenohex.Session.put(context.session_id, "key/expr", "payload")
+
+    assert {:ok, %Zenohex.Sample{payload: "payload"}} = Zenohex.Receiver.recv(subscriber_id, 100)
+    refute_received %Zenohex.Sample{}
+  end
+
+  test "try_recv/1 returns :empty when nothing was received", context do
+    {:ok, subscriber_id} =
+      Zenohex.Session.declare_pull_subscriber(context.session_id, "key/expr", {:fifo, 16})
+
+    assert {:error, :empty} = Zenohex.Receiver.try_recv(subscriber_id)
+  end
+
+  test "recv_many/3 with ring handler keeps only the latest samples", context do
+    {:ok, subscriber_id} =
+      Zenohex.Session.declare_pull_subscriber(context.session_id, "key/expr", {:ring, 2})
+
+    for i <- 1..5 do
+      :ok = Zenohex.Session.put(context.session_id, "key/expr", "#{i}")
+    end
+
+    assert {:ok, [%Zenohex.Sample{payload: "4"}, %Zenohex.Sample{payload: "5"}]} =
+             Zenohex.Receiver.recv_many(subscriber_id, 10, 100)
+  end
+
+  test "recv_many/3 with fifo handler drops new samples when full", context do
+    {:ok, subscriber_id} =
+      Zenohex.Session.declare_pull_subscriber(context.session_id, "key/expr", {:fifo, 2})
+
+    # WHY: A full FIFO must not block the put, which runs on the caller's scheduler.
+    for i <- 1..5 do
+      :ok = Zenohex.Session.put(context.session_id, "key/expr", "#{i}")
+    end
+
+    assert {:ok, [%Zenohex.Sample{payload: "1"}, %Zenohex.Sample{payload: "2"}]} =
+             Zenohex.Receiver.recv_many(subscriber_id, 10, 100)
+  end
+
+  test "declare_pull_subscriber/4 raises for a zero capacity handler", context do
+    for handler <- [{:fifo, 0}, {:ring, 0}] do
+      assert_raise ArgumentError, fn ->
+        Zenohex.Session.declare_pull_subscriber(context.session_id, "key/expr", handler)
+      end
+    end
+  end
+
+  test "recv/2 receives queries from a pull queryable", context do
+    {:ok, queryable_id} =
+      Zenohex.Session.declare_pull_queryable(context.session_id, "key/expr/**", {:fifo, 16})
+
+    {:ok, receiver} = Zenohex.Session.get_pull(context.session_id, "key/expr/1", {:fifo, 16})
+
+    assert {:ok, %Zenohex.Query{zenoh_query: zenoh_query}} =
+             Zenohex.Receiver.recv(queryable_id, 100)
+
+    :ok = Zenohex.Query.reply(zenoh_query, "key/expr/1", "payload")
+
+    assert {:ok, [%Zenohex.Sample{payload: "payload"}]} =
+             Zenohex.Receiver.recv_many(receiver, 10, 100)
+  end
+
+  test "recv/2 returns error for an entity without receiver", context do
+    {:ok, subscriber_id} =
+      Zenohex.Session.declare_subscriber(context.session_id, "key/expr", self())
+
+    assert {:error, :unsupported_entity} = Zenohex.Receiver.recv(subscriber_id, 10)
+  end
+end
@@ -0,0 +1,85 @@
+defmodule Zenohex.SelectorTest do
+  use ExUnit.Case
+
+  alias Zenohex.Selector
+  alias Zenohex.Selector.TimeRange
+
+  test "split/1" do
+    assert {:ok, {"demo/**", "a=1;b=2"}} = Selector.split("demo/**?a=1;b=2")
+    assert {:ok, {"demo/**", ""}} = Selector.split("demo/**")
+    assert {:error, _reason} = Selector.split("demo//a?a=1")
+  end
+
+  test "parse_parameters/1 and parameter_values/2" do
+    assert Selector.parse_parameters("a=1;flag;b=x=y;a=2") ==
+             %{"a" => "1", "flag" => "", "b" => "x=y"}
+
+    assert Selector.parse_parameters("") == %{}
+
+    assert Selector.parameter_values("fields=a|b;limit=10", "fields") == ["a", "b"]
+    assert Selector.parameter_values("fields=a|b;limit=10", "missing") == []
+  end
+
+  test "build_parameters/1" do
+    assert {:ok, "limit=10;fields=a|b;flag"} =
+             Selector.build_parameters(limit: 10, fields: ["a", "b"], flag: "")
+
+    assert {:ok, parameters} = Selector.build_parameters(%{"_time" => "[now(-1h)..]"})
+    assert Selector.parse_parameters(parameters) == %{"_time" => "[now(-1h)..]"}
+
+    assert {:error, _reason} = Selector.build_parameters(%{"a" => "x;y"})
+    assert {:error, _reason} = Selector.build_parameters(%{"a" => ["x|y"]})
+    assert {:error, _reason} = Selector.build_parameters(%{"a=b" => "x"})
+    assert {:error, _reason} = Selector.build_parameters(%{"" => "x"})
+  end
+
+  test "time_range/1" do
+    assert {:ok, %TimeRange{start: {:inclusive, {:now, -3600.0}}, end: :unbounded}} =
+             Selector.time_range("limit=10;_time=[now(-1h)..]")
+
+    assert {:ok, nil} = Selector.time_range("limit=10")
+    assert {:error, _reason} = Selector.time_range("_time=yesterday")
+    assert {:error, _reason} = Selector.time_range("_time=[now(s)..]")
+    assert {:error, _reason} = Selector.time_range("_time=[2020-01-01T00:00:00Z;-1s]")
+  end
+
+  test "TimeRange.parse/1" do
+    assert {:ok,
+            %TimeRange{
+              start: {:inclusive, ~U[2025-07-16 00:00:00.000000Z]},
+              end: {:exclusive, ~U[2025-07-16 01:00:00.000000Z]}
+            }} = TimeRange.parse("[2025-07-16T00:00:00Z..2025-07-16T01:00:00Z[")
+
+    assert {:ok,
+            %TimeRange{
+              start: {:exclusive, ~U[2025-07-16 00:00:00.000000Z]},
+              end: {:inclusive, ~U[2025-07-16 00:30:00.000000Z]}
+            }} = TimeRange.parse("]2025-07-16T00:00:00Z;30m]")
+
+    assert {:ok, %TimeRange{start: :unbounded, end: :unbounded}} = TimeRange.parse("[..]")
+    assert {:error, _reason} = TimeRange.parse("2025-07-16T00:00:00Z")
+
+    # WHY: Fixed times are nanoseconds in an i64, which only spans the years 1677 to 2262.
+    assert {:error, _reason} = TimeRange.parse("[2300-01-01T00:00:00Z..]")
+    assert {:error, _reason} = TimeRange.parse("[..1600-01-01T00:00:00Z]")
+
+    # WHY: zenoh panics on these, they must be errors since `_time` may come from remote queries.
+    assert {:error, _reason} = TimeRange.parse("[now(s)..]")
+    assert {:error, _reason} = TimeRange.parse("[2020-01-01T00:00:00Z;-1s]")
+  end
+
+  test "TimeRange.resolve/2 and TimeRange.contains?/3" do
+    now = ~U[2025-07-16 01:00:00.000000Z]
+    {:ok, time_range} = TimeRange.parse("[now(-1h)..now()[")
+
+    assert %TimeRange{
+             start: {:inclusive, ~U[2025-07-16 00:00:00.000000Z]},
+             end: {:exclusive, ^now}
+           } = TimeRange.resolve(time_range, now)
+
+    assert TimeRange.contains?(time_range, ~U[2025-07-16 00:00:00Z], now)
+    assert TimeRange.contains?(time_range, ~U[2025-07-16 00:59:59Z], now)
+    refute TimeRange.contains?(time_range, now, now)
+    refute TimeRange.contains?(time_range, ~U[2025-07-15 23:59:59Z], now)
+  end
+end
@@ -0,0 +1,64 @@
+defmodule Zenohex.SerializationTest do
+  use ExUnit.Case
+
+  alias Zenohex.Serialization
+
+  test "serialize/2 produces the zenoh-ext wire format" do
+    assert {:ok, <<1, 0, 0, 0>>} = Serialization.serialize(1, :i32)
+    assert {:ok, <<255, 255>>} = Serialization.serialize(-1, :i16)
+    assert {:ok, <<1.5::little-float-32>>} = Serialization.serialize(1.5, :f32)
+    assert {:ok, <<1>>} = Serialization.serialize(true, :bool)
+    assert {:ok, <<2, "hi">>} = Serialization.serialize("hi", :string)
+    assert {:ok, <<3, 0, 1, 2>>} = Serialization.serialize(<<0, 1, 2>>, :bytes)
+
+    assert {:ok, <<1, 2, 0>>} = Serialization.serialize({1, 2}, {:tuple, [:u8, :u16]})
+
+    assert {:ok, <<2, 1.0::little-float-64, 2.0::little-float-64>>} =
+             Serialization.serialize([1.0, 2.0], {:list, :f64})
+
+    assert {:ok, <<1, 1, "a", 1>>} = Serialization.serialize(%{"a" => 1}, {:map, :string, :u8})
+  end
+
+  test "deserialize/2 decodes what serialize/2 encodes" do
+    type = {:tuple, [:i64, :string, {:list, {:map, :string, :f64}}, :bytes]}
+    term = {-42, "zenoh", [%{"x" => 1.0, "y" => 2.0}, %{}], <<0, 255>>}
+
+    {:ok, binary} = Serialization.serialize(term, type)
+
+    assert {:ok, ^term} = Serialization.deserialize(binary, type)
+  end
+
+  test "deserialize/2 returns error on malformed input" do
+    assert {:error, _reason} = Serialization.deserialize(<<1, 0>>, :i32)
+    assert {:error, _reason} = Serialization.deserialize(<<1, 0, 0, 0, 0>>, :i32)
+  end
+
+  test "deserialize/2 returns error on a truncated map or list with a huge length" do
+    huge_length = <<0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F>>
+
+    assert {:error, _reason} =
+             Serialization.deserialize(huge_length <> <<1, "a">>, {:map, :string, :u8})
+
+    assert {:error, _reason} = Serialization.deserialize(huge_length <> <<1>>, {:list, :u8})
+  end
+
+  test "deserialize/2 returns error for list and map elements which consume no bytes" do
+    huge_length = <<0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F>>
+
+    assert {:error, _reason} = Serialization.deserialize(huge_length, {:list, {:tuple, []}})
+
+    assert {:error, _reason} =
+             Serialization.deserialize(huge_length, {:list, {:tuple, [{:tuple, []}]}})
+
+    assert {:error, _reason} =
+             Serialization.deserialize(huge_length, {:map, {:tuple, []}, {:tuple, []}})
+
+    assert {:ok, %{{} => 1}} =
+             Serialization.deserialize(<<1, 1>>, {:map, {:tuple, []}, :u8})
+  end
+
+  test "serialize/2 raises on type mismatch" do
+    assert_raise ArgumentError, fn -> Serialization.serialize("1", :i32) end
+    assert_raise ArgumentError, fn -> Serialization.serialize({1}, {:tuple, [:u8, :u8]}) end
+  end
+end
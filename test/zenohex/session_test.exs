@@ -1,6 +1,8 @@
 defmodule Zenohex.SessionTest do
   use ExUnit.Case
 
+  import Bitwise
+
   setup do
     {:ok, session_id} =
       Zenohex.Config.default()
@@ -48,6 +50,23 @@ defmodule Zenohex.SessionTest do
     assert Zenohex.Session.close(session_id) == {:error, "session not found"}
   end
 
+  test "close/1 and drop release the listening port" do
+    {:ok, session_id} = open_listening_session("tcp/127.0.0.1:0")
+    {:ok, %Zenohex.Session.Info{locators: [locator]}} = Zenohex.Session.info(session_id)
+
+    assert :ok = Zenohex.Session.close(session_id)
+
+    # WHY: A port can only be listened on again once the session listening on it released it.
+    test_pid = self()
+
+    # The session id is only held by this process, so it is garbage-collected when it exits.
+    spawn(fn -> send(test_pid, {:opened, match?({:ok, _}, open_listening_session(locator))}) end)
+    assert_receive {:opened, true}, 1000
+
+    assert {:ok, session_id} = open_listening_session_eventually(locator, 10)
+    :ok = Zenohex.Session.close(session_id)
+  end
+
   test "put/3", context do
     assert Zenohex.Session.put(context.session_id, "key/expr", "payload") == :ok
   end
@@ -70,18 +89,318 @@ defmodule Zenohex.SessionTest do
     assert_receive %Zenohex.Sample{kind: :delete, key_expr: "key/expr", timestamp: ^timestamp}
   end
 
+  test "put/4 accepts source_info", context do
+    {:ok, subscriber_id} =
+      Zenohex.Session.declare_subscriber(context.session_id, "key/expr", self())
+
+    on_exit(fn -> :ok = Zenohex.Subscriber.undeclare(subscriber_id) end)
+
+    {:ok, %Zenohex.Session.Info{zid: zid}} = Zenohex.Session.info(context.session_id)
+    source_id = %Zenohex.EntityGlobalId{zid: zid, eid: 1}
+    source_info = %Zenohex.SourceInfo{source_id: source_id, source_sn: 42}
+
+    assert :ok =
+             Zenohex.Session.put(context.session_id, "key/expr", "payload",
+               source_info: source_info
+             )
+
+    assert_receive %Zenohex.Sample{source_info: ^source_info}
+
+    :ok = Zenohex.Session.put(context.session_id, "key/expr", "payload")
+    assert_receive %Zenohex.Sample{source_info: nil}
+  end
+
+  test "put/4 and declare_publisher/3 accept reliability", context do
+    {:ok, subscriber_id} =
+      Zenohex.Session.declare_subscriber(context.session_id, "key/expr", self())
+
+    on_exit(fn -> :ok = Zenohex.Subscriber.undeclare(subscriber_id) end)
+
+    :ok = Zenohex.Session.put(context.session_id, "key/expr", "reliable")
+    assert_receive %Zenohex.Sample{payload: "reliable", reliability: :reliable}
+
+    :ok =
+      Zenohex.Session.put(context.session_id, "key/expr", "best_effort",
+        reliability: :best_effort
+      )
+
+    assert_receive %Zenohex.Sample{payload: "best_effort", reliability: :best_effort}
+
+    {:ok, publisher_id} =
+      Zenohex.Session.declare_publisher(context.session_id, "key/expr",
+        reliability: :best_effort
+      )
+
+    :ok = Zenohex.Publisher.put(publisher_id, "publisher")
+    assert_receive %Zenohex.Sample{payload: "publisher", reliability: :best_effort}
+  end
+
   test "get/3", context do
     assert {:error, _} = Zenohex.Session.get(context.session_id, "key/expr", 100)
   end
 
+  test "get_async/4 accepts a declared key expression with parameters", context do
+    {:ok, key_expr} = Zenohex.Session.declare_keyexpr(context.session_id, "fleet/robot/1")
+    {:ok, _queryable_id} = Zenohex.Session.declare_queryable(context.session_id, key_expr)
+
+    {:ok, _ref} =
+      Zenohex.Session.get_async(context.session_id, key_expr, self(), parameters: "limit=10")
+
+    assert_receive %Zenohex.Query{key_expr: "fleet/robot/1", parameters: "limit=10"}
+
+    {:ok, _ref} =
+      Zenohex.Session.get_async(context.session_id, "fleet/robot/1?a=1", self(),
+        parameters: "limit=10"
+      )
+
+    assert_receive %Zenohex.Query{key_expr: "fleet/robot/1", parameters: "a=1;limit=10"}
+  end
+
+  test "get_async/2 sends done message without replies", context do
+    assert {:ok, ref} =
+             Zenohex.Session.get_async(context.session_id, "key/expr", self(),
+               query_timeout: 100
+             )
+
+    assert_receive {:zenohex_get_done, ^ref}, 1000
+    refute_received {:zenohex_get_reply, ^ref, _}
+  end
+
+  test "get_async/4 sends no done message when the get fails to start", context do
+    ref = make_ref()
+
+    assert_raise ArgumentError, fn ->
+      Zenohex.Session.get_async(context.session_id, "key/expr", self(), ref: ref, parameters: 1)
+    end
+
+    refute_receive {:zenohex_get_done, ^ref}, 200
+  end
+
   test "new_timestamp/1", context do
-    assert {:ok, zenoh_timestamp} = Zenohex.Session.new_timestamp(context.session_id)
-    assert [timestamp, _zenoh_id_string] = String.split(zenoh_timestamp, "/")
-    assert {:ok, %DateTime{}, 0} = DateTime.from_iso8601(timestamp)
+    assert {:ok, %Zenohex.Timestamp{id: id} = timestamp1} =
+             Zenohex.Session.new_timestamp(context.session_id)
+
+    assert {:ok, %Zenohex.Session.Info{zid: ^id}} = Zenohex.Session.info(context.session_id)
+
+    {:ok, timestamp2} = Zenohex.Session.new_timestamp(context.session_id)
+    assert :lt = Zenohex.Timestamp.compare(timestamp1, timestamp2)
+  end
+
+  test "update_with_timestamp/2", context do
+    {:ok, %Zenohex.Timestamp{id: id} = timestamp} =
+      Zenohex.Session.new_timestamp(context.session_id)
+
+    future = %Zenohex.Timestamp{timestamp | time: timestamp.time + (1 <<< 30)}
+    assert :ok = Zenohex.Session.update_with_timestamp(context.session_id, future)
+
+    {:ok, next} = Zenohex.Session.new_timestamp(context.session_id)
+    assert %Zenohex.Timestamp{id: ^id} = next
+    assert :gt = Zenohex.Timestamp.compare(next, future)
+
+    too_far = %Zenohex.Timestamp{timestamp | time: timestamp.time + (3600 <<< 32)}
+    assert {:error, _reason} = Zenohex.Session.update_with_timestamp(context.session_id, too_far)
   end
 
   test "info/1", context do
-    assert {:ok, %Zenohex.Session.Info{}} = Zenohex.Session.info(context.session_id)
+    assert {:ok, %Zenohex.Session.Info{whatami: :peer}} = Zenohex.Session.info(context.session_id)
+  end
+
+  test "config/1 and config_get_json/2", context do
+    assert {:ok, config} = Zenohex.Session.config(context.session_id)
+    assert {:ok, "0"} = Zenohex.Config.get_json(config, "scouting/delay")
+
+    assert {:ok, "0"} = Zenohex.Session.config_get_json(context.session_id, "scouting/delay")
+    assert {:error, _reason} = Zenohex.Session.config_get_json(context.session_id, "unknown")
+  end
+
+  test "config_insert_json5/3 returns an error for a key zenoh cannot change live", context do
+    assert {:error, _reason} =
+             Zenohex.Session.config_insert_json5(context.session_id, "mode", "client")
+
+    assert {:ok, ~s("peer")} = Zenohex.Session.config_get_json(context.session_id, "mode")
+  end
+
+  test "connect/2 connects a running session to a new endpoint" do
+    {:ok, config} =
+      Zenohex.Config.default()
+      |> Zenohex.Test.Support.TestHelper.scouting_delay(0)
+      |> Zenohex.Config.insert_json5("scouting/multicast/enabled", "false")
+
+    {:ok, listening_config} =
+      Zenohex.Config.insert_json5(config, "listen/endpoints", ["tcp/127.0.0.1:0"])
+
+    {:ok, listening_session_id} = Zenohex.Session.open(listening_config)
+    on_exit(fn -> Zenohex.Session.close(listening_session_id) end)
+
+    {:ok, %Zenohex.Session.Info{zid: listening_zid, locators: [locator]}} =
+      Zenohex.Session.info(listening_session_id)
+
+    {:ok, connecting_config} = Zenohex.Config.insert_json5(config, "listen/endpoints", [])
+    {:ok, session_id} = Zenohex.Session.open(connecting_config)
+    on_exit(fn -> Zenohex.Session.close(session_id) end)
+
+    assert {:ok, %Zenohex.Session.Info{transports: []}} = Zenohex.Session.info(session_id)
+
+    assert :ok = Zenohex.Session.connect(session_id, locator)
+
+    assert {:ok, %Zenohex.Session.Info{transports: [transport]}} =
+             Zenohex.Session.info(session_id)
+
+    assert %Zenohex.Session.Transport{zid: ^listening_zid, whatami: :peer} = transport
+
+    assert {:error, _reason} = Zenohex.Session.connect(session_id, "tcp/127.0.0.1:1")
+    assert {:error, _reason} = Zenohex.Session.connect(session_id, "not an endpoint")
+  end
+
+  test "info/1 reports bound locators and transports" do
+    {:ok, config} =
+      Zenohex.Config.default()
+      |> Zenohex.Test.Support.TestHelper.scouting_delay(0)
+      |> Zenohex.Config.insert_json5("scouting/multicast/enabled", "false")
+
+    {:ok, listening_config} =
+      Zenohex.Config.insert_json5(config, "listen/endpoints", ["tcp/127.0.0.1:0"])
+
+    {:ok, session_id} = Zenohex.Session.open(listening_config)
+    on_exit(fn -> Zenohex.Session.close(session_id) end)
+
+    {:ok, %Zenohex.Session.Info{zid: zid, locators: [locator]}} =
+      Zenohex.Session.info(session_id)
+
+    assert "tcp/127.0.0.1:" <> port = locator
+    assert String.to_integer(port) > 0
+
+    {:ok, connecting_config} =
+      Zenohex.Config.insert_json5(config, "connect/endpoints", [locator])
+
+    {:ok, connecting_config} =
+      Zenohex.Config.insert_json5(connecting_config, "listen/endpoints", [])
+
+    {:ok, remote_session_id} = Zenohex.Session.open(connecting_config)
+    on_exit(fn -> Zenohex.Session.close(remote_session_id) end)
+
+    {:ok, %Zenohex.Session.Info{transports: [transport]}} =
+      Zenohex.Session.info(remote_session_id)
+
+    assert %Zenohex.Session.Transport{zid: ^zid, whatami: :peer, multicast: false} = transport
+
+    assert [%Zenohex.Session.Link{protocol: "tcp", dst: ^locator, group: nil}] =
+             transport.links
+  end
+
+  test "entities/1", context do
+    assert {:ok, []} = Zenohex.Session.entities(context.session_id)
+
+    {:ok, publisher_id} =
+      Zenohex.Session.declare_publisher(context.session_id, "key/expr", priority: :real_time)
+
+    {:ok, _subscriber_id} = Zenohex.Session.declare_subscriber(context.session_id, "key/**")
+
+    {:ok, entities} = Zenohex.Session.entities(context.session_id)
+    pid = self()
+
+    assert [
+             %Zenohex.Session.Entity{
+               kind: :publisher,
+               key_expr: "key/expr",
+               pid: nil,
+               opts: publisher_opts,
+               id: id
+             },
+             %Zenohex.Session.Entity{
+               kind: :subscriber,
+               key_expr: "key/**",
+               pid: ^pid,
+               opts: [allowed_origin: :any]
+             }
+           ] = Enum.sort_by(entities, & &1.kind)
+
+    # the given options and the zenoh defaults of the others
+    assert Enum.sort(publisher_opts) == [
+             allowed_destination: :any,
+             congestion_control: :drop,
+             encoding: "zenoh/bytes",
+             express: false,
+             priority: :real_time,
+             reliability: :reliable
+           ]
+
+    # dropping the listed ids does not undeclare the entities
+    {:ok, [_, _]} = Zenohex.Session.entities(context.session_id)
+    :erlang.garbage_collect()
+    assert {:ok, [_, _]} = Zenohex.Session.entities(context.session_id)
+
+    assert :ok = Zenohex.Publisher.undeclare(id)
+    assert {:error, _} = Zenohex.Publisher.undeclare(publisher_id)
+  end
+
+  test "entities/1 reports the query timeout of the session config for queriers" do
+    {:ok, config} =
+      Zenohex.Config.default()
+      |> Zenohex.Test.Support.TestHelper.scouting_delay(0)
+      |> Zenohex.Config.insert_json5("queries_default_timeout", "1234")
+
+    {:ok, session_id} = Zenohex.Session.open(config)
+    {:ok, _querier_id} = Zenohex.Session.declare_querier(session_id, "key/expr", target: :all)
+
+    {:ok, [%Zenohex.Session.Entity{kind: :querier, opts: opts}]} =
+      Zenohex.Session.entities(session_id)
+
+    assert opts[:query_timeout] == 1234
+    assert opts[:target] == :all
+    assert opts[:congestion_control] == :block
+
+    :ok = Zenohex.Session.close(session_id)
+  end
+
+  test "undeclare_all/2", context do
+    {:ok, _} = Zenohex.Session.declare_publisher(context.session_id, "robot/1/cmd")
+    {:ok, subscriber_id} = Zenohex.Session.declare_subscriber(context.session_id, "robot/1/state")
+    {:ok, _} = Zenohex.Session.declare_subscriber(context.session_id, "robot/2/state")
+    {:ok, _} = Zenohex.Session.declare_queryable(context.session_id, "robot/1/config")
+
+    assert {:ok, 2} =
+             Zenohex.Session.undeclare_all(context.session_id,
+               kind: [:subscriber, :queryable],
+               key_expr: "robot/1/**"
+             )
+
+    assert {:error, _} = Zenohex.Subscriber.undeclare(subscriber_id)
+
+    {:ok, entities} = Zenohex.Session.entities(context.session_id)
+
+    assert ["robot/1/cmd", "robot/2/state"] =
+             entities |> Enum.map(& &1.key_expr) |> Enum.sort()
+
+    assert {:ok, 2} = Zenohex.Session.undeclare_all(context.session_id)
+    assert {:ok, []} = Zenohex.Session.entities(context.session_id)
+  end
+
+  test "declare_keyexpr/2", context do
+    {:ok, key_expr} = Zenohex.Session.declare_keyexpr(context.session_id, "fleet/robot/1/pose")
+    {:ok, subscriber_id} = Zenohex.Session.declare_subscriber(context.session_id, key_expr)
+    {:ok, publisher_id} = Zenohex.Session.declare_publisher(context.session_id, key_expr)
+
+    assert :ok = Zenohex.Session.put(context.session_id, key_expr, "put")
+    assert_receive %Zenohex.Sample{key_expr: "fleet/robot/1/pose", payload: "put"}
+
+    assert :ok = Zenohex.Publisher.put(publisher_id, "publisher")
+    assert_receive %Zenohex.Sample{key_expr: "fleet/robot/1/pose", payload: "publisher"}
+
+    {:ok, entities} = Zenohex.Session.entities(context.session_id)
+    assert Enum.all?(entities, &(&1.key_expr == "fleet/robot/1/pose"))
+
+    assert :ok = Zenohex.KeyExpr.undeclare(key_expr)
+    assert {:error, _} = Zenohex.KeyExpr.undeclare(key_expr)
+
+    assert :ok = Zenohex.Session.put(context.session_id, key_expr, "undeclared")
+    assert_receive %Zenohex.Sample{key_expr: "fleet/robot/1/pose", payload: "undeclared"}
+
+    :ok = Zenohex.Subscriber.undeclare(subscriber_id)
+  end
+
+  test "declare_keyexpr/2 returns an error for an invalid key expression", context do
+    assert {:error, _} = Zenohex.Session.declare_keyexpr(context.session_id, "key/expr?")
   end
 
   test "declare_publisher/2", context do
@@ -93,4 +412,27 @@ defmodule Zenohex.SessionTest do
     assert {:ok, _querier_id} =
              Zenohex.Session.declare_querier(context.session_id, "key/expr")
   end
+
+  defp open_listening_session(endpoint) do
+    {:ok, config} =
+      Zenohex.Config.default()
+      |> Zenohex.Test.Support.TestHelper.scouting_delay(0)
+      |> Zenohex.Config.insert_json5("scouting/multicast/enabled", "false")
+
+    {:ok, config} = Zenohex.Config.insert_json5(config, "listen/endpoints", [endpoint])
+
+    Zenohex.Session.open(config)
+  end
+
+  # WHY: A dropped session is closed when the BEAM collects its id, not right away.
+  defp open_listening_session_eventually(endpoint, retries) do
+    case open_listening_session(endpoint) do
+      {:error, _reason} when retries > 0 ->
+        Process.sleep(100)
+        open_listening_session_eventually(endpoint, retries - 1)
+
+      result ->
+        result
+    end
+  end
 end
@@ -0,0 +1,54 @@
+defmodule Zenohex.ShmTest do
+  use ExUnit.Case
+
+  setup do
+    {:ok, session_id} =
+      Zenohex.Config.default()
+      |> Zenohex.Test.Support.TestHelper.scouting_delay(0)
+      |> Zenohex.Session.open()
+
+    on_exit(fn -> Zenohex.Session.close(session_id) end)
+
+    {:ok, provider} = Zenohex.Shm.provider_new(65_536)
+
+    %{session_id: session_id, provider: provider}
+  end
+
+  test "alloc/2 and write/3", context do
+    {:ok, buffer} = Zenohex.Shm.alloc(context.provider, 7)
+
+    assert 7 = Zenohex.Shm.len(buffer)
+    assert :ok = Zenohex.Shm.write(buffer, "pay")
+    assert :ok = Zenohex.Shm.write(buffer, "load", 3)
+    assert_raise ArgumentError, fn -> Zenohex.Shm.write(buffer, "payload", 1) end
+  end
+
+  test "Session.put/4 publishes a shm buffer", context do
+    {:ok, _subscriber_id} =
+      Zenohex.Session.declare_subscriber(context.session_id, "key/expr", self())
+
+    {:ok, buffer} = Zenohex.Shm.alloc(context.provider, 7)
+    :ok = Zenohex.Shm.write(buffer, "payload")
+
+    assert :ok = Zenohex.Session.put(context.session_id, "key/expr", buffer)
+    assert_receive %Zenohex.Sample{payload: "payload", shm: true}
+
+    # the buffer was moved into zenoh on put
+    assert {:error, _reason} = Zenohex.Session.put(context.session_id, "key/expr", buffer)
+  end
+
+  test "Publisher.put/3 publishes a shm buffer", context do
+    {:ok, _subscriber_id} =
+      Zenohex.Session.declare_subscriber(context.session_id, "key/expr", self())
+
+    {:ok, publisher_id} = Zenohex.Session.declare_publisher(context.session_id, "key/expr")
+    {:ok, buffer} = Zenohex.Shm.alloc(context.provider, 7)
+    :ok = Zenohex.Shm.write(buffer, "payload")
+
+    assert :ok = Zenohex.Publisher.put(publisher_id, buffer)
+    assert_receive %Zenohex.Sample{payload: "payload", shm: true}
+
+    :ok = Zenohex.Session.put(context.session_id, "key/expr", "payload")
+    assert_receive %Zenohex.Sample{payload: "payload", shm: false}
+  end
+end
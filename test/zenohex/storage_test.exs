@@ -0,0 +1,98 @@
+defmodule Zenohex.StorageTest do
+  use ExUnit.Case
+
+  setup do
+    {:ok, session_id} =
+      Zenohex.Config.default()
+      |> Zenohex.Test.Support.TestHelper.scouting_delay(0)
+      |> Zenohex.Session.open()
+
+    on_exit(fn -> Zenohex.Session.close(session_id) end)
+
+    {:ok, storage} = Zenohex.Storage.declare(session_id, "storage/**")
+
+    %{session_id: session_id, storage: storage}
+  end
+
+  test "keeps the latest sample per key and answers queries", context do
+    :ok = Zenohex.Session.put(context.session_id, "storage/a", "a1")
+    :ok = Zenohex.Session.put(context.session_id, "storage/a", "a2")
+    :ok = Zenohex.Session.put(context.session_id, "storage/b", "b1")
+
+    assert {:ok, keys} = Zenohex.Storage.keys(context.storage)
+    assert Enum.sort(keys) == ["storage/a", "storage/b"]
+
+    assert {:ok, [%Zenohex.Sample{key_expr: "storage/a", payload: "a2"}]} =
+             Zenohex.Storage.get(context.storage, "storage/a")
+
+    assert {:ok, samples} = Zenohex.Session.get(context.session_id, "storage/**", 1000)
+    assert Enum.sort(Enum.map(samples, & &1.payload)) == ["a2", "b1"]
+  end
+
+  test "delete samples remove the key", context do
+    :ok = Zenohex.Session.put(context.session_id, "storage/a", "a")
+    :ok = Zenohex.Session.delete(context.session_id, "storage/a")
+
+    assert {:ok, []} = Zenohex.Storage.keys(context.storage)
+    assert {:ok, []} = Zenohex.Storage.get(context.storage)
+  end
+
+  test "orders samples with explicit timestamps, whatever their arrival order", context do
+    {:ok, %Zenohex.Session.Info{zid: zid}} = Zenohex.Session.info(context.session_id)
+
+    [t1, t2, t3] =
+      for second <- 1..3 do
+        {:ok, timestamp} =
+          Zenohex.Timestamp.from_datetime(DateTime.from_unix!(1_750_000_000 + second), zid)
+
+        timestamp
+      end
+
+    :ok = Zenohex.Session.put(context.session_id, "storage/a", "a2", timestamp: t2)
+    :ok = Zenohex.Session.put(context.session_id, "storage/a", "a1", timestamp: t1)
+
+    assert {:ok, [%Zenohex.Sample{payload: "a2", timestamp: ^t2}]} =
+             Zenohex.Storage.get(context.storage, "storage/a")
+
+    :ok = Zenohex.Session.delete(context.session_id, "storage/a", timestamp: t3)
+    :ok = Zenohex.Session.put(context.session_id, "storage/a", "a2", timestamp: t2)
+
+    assert {:ok, []} = Zenohex.Storage.get(context.storage, "storage/a")
+  end
+
+  test "tombstones are collected after tombstone_lifespan", context do
+    {:ok, storage} =
+      Zenohex.Storage.declare(context.session_id, "tombstone/**", tombstone_lifespan: 0)
+
+    {:ok, %Zenohex.Session.Info{zid: zid}} = Zenohex.Session.info(context.session_id)
+    {:ok, t1} = Zenohex.Timestamp.from_datetime(DateTime.from_unix!(1_750_000_001), zid)
+    {:ok, t2} = Zenohex.Timestamp.from_datetime(DateTime.from_unix!(1_750_000_002), zid)
+
+    :ok = Zenohex.Session.delete(context.session_id, "tombstone/a", timestamp: t2)
+    :ok = Zenohex.Session.put(context.session_id, "tombstone/a", "a1", timestamp: t1)
+
+    assert {:ok, [%Zenohex.Sample{payload: "a1"}]} = Zenohex.Storage.get(storage, "tombstone/a")
+  end
+
+  test "purge/2", context do
+    :ok = Zenohex.Session.put(context.session_id, "storage/a", "a")
+    :ok = Zenohex.Session.put(context.session_id, "storage/b", "b")
+
+    assert {:ok, 1} = Zenohex.Storage.purge(context.storage, "storage/a")
+    assert {:ok, ["storage/b"]} = Zenohex.Storage.keys(context.storage)
+    assert {:ok, 1} = Zenohex.Storage.purge(context.storage)
+    assert {:ok, []} = Zenohex.Storage.keys(context.storage)
+  end
+
+  test "undeclare/1", context do
+    assert :ok = Zenohex.Storage.undeclare(context.storage)
+    assert {:error, _} = Zenohex.Storage.undeclare(context.storage)
+  end
+
+  test "raises on invalid key expressions", context do
+    invalid = "invalid/key/expr?"
+
+    assert_raise ArgumentError, fn -> Zenohex.Storage.get(context.storage, invalid) end
+    assert_raise ArgumentError, fn -> Zenohex.Storage.purge(context.storage, invalid) end
+  end
+end
@@ -23,4 +23,18 @@ defmodule Zenohex.SubscriberTest do
     # confirm already undeclared
     assert {:error, _} = Zenohex.Subscriber.undeclare(context.subscriber_id)
   end
+
+  test "samples are delivered in publication order", context do
+    for i <- 1..100 do
+      :ok = Zenohex.Session.put(context.session_id, "key/expr", "#{i}")
+    end
+
+    payloads =
+      for _ <- 1..100 do
+        assert_receive %Zenohex.Sample{payload: payload}
+        payload
+      end
+
+    assert payloads == Enum.map(1..100, &"#{&1}")
+  end
 end
@@ -0,0 +1,41 @@
+defmodule Zenohex.TimestampTest do
+  use ExUnit.Case
+
+  import Bitwise
+
+  @id "208a2ec783ec4527a39cc1d5559c70e9"
+
+  test "from_datetime/2 and to_datetime/1" do
+    datetime = ~U[2025-07-16 01:34:56.871273Z]
+    assert {:ok, %Zenohex.Timestamp{id: @id} = timestamp} =
+             Zenohex.Timestamp.from_datetime(datetime, @id)
+
+    assert Zenohex.Timestamp.to_datetime(timestamp) == datetime
+  end
+
+  test "from_datetime/2 returns error out of the NTP64 range" do
+    assert {:ok, _timestamp} = Zenohex.Timestamp.from_datetime(~U[2106-02-07 06:28:15Z], @id)
+    assert {:error, _reason} = Zenohex.Timestamp.from_datetime(~U[2106-02-07 06:28:16Z], @id)
+    assert {:error, _reason} = Zenohex.Timestamp.from_datetime(~U[9999-12-31 23:59:59Z], @id)
+    assert {:error, _reason} = Zenohex.Timestamp.from_datetime(~U[1969-12-31 23:59:59Z], @id)
+  end
+
+  test "to_string/1 and parse/1" do
+    string = "2025-07-16T01:34:56.871273403Z/#{@id}"
+
+    assert {:ok, timestamp} = Zenohex.Timestamp.parse(string)
+    assert Zenohex.Timestamp.to_string(timestamp) == string
+    assert {:error, _reason} = Zenohex.Timestamp.parse("not a timestamp")
+  end
+
+  test "compare/2 orders by time, then by id" do
+    timestamp = %Zenohex.Timestamp{time: 1 <<< 32, id: "1"}
+
+    assert :eq = Zenohex.Timestamp.compare(timestamp, timestamp)
+    assert :lt = Zenohex.Timestamp.compare(timestamp, %{timestamp | time: timestamp.time + 1})
+    assert :lt = Zenohex.Timestamp.compare(timestamp, %{timestamp | id: "2"})
+
+    assert [^timestamp, _] =
+             Enum.sort([%{timestamp | id: "2"}, timestamp], Zenohex.Timestamp)
+  end
+end
@@ -0,0 +1,20 @@
+defmodule Zenohex.ZenohIdTest do
+  use ExUnit.Case
+
+  test "parse/1" do
+    assert {:ok, "208a2ec783ec4527a39cc1d5559c70e9"} =
+             Zenohex.ZenohId.parse("208a2ec783ec4527a39cc1d5559c70e9")
+
+    assert {:error, _reason} = Zenohex.ZenohId.parse("208A2EC783EC4527A39CC1D5559C70E9")
+    assert {:error, _reason} = Zenohex.ZenohId.parse("not a zid")
+  end
+
+  test "compare/2" do
+    assert :eq = Zenohex.ZenohId.compare("1", "1")
+    assert :lt = Zenohex.ZenohId.compare("1", "2")
+    assert :gt = Zenohex.ZenohId.compare("2", "1")
+    assert ["1", "2"] = Enum.sort(["2", "1"], Zenohex.ZenohId)
+
+    assert_raise ArgumentError, fn -> Zenohex.ZenohId.compare("not a zid", "1") end
+  end
+end